@@ -0,0 +1,20 @@
+use std::{env, fs, path::Path};
+
+use hazard_generator::{HazardsProducer, TemplateType};
+
+fn main() {
+    // Generate the hazards APIs from the ontology of the repository.
+    hazard_generator::build::generate(["../../ontology.jsonld"]).unwrap();
+
+    // Generate the same APIs with identifiers above 64, so that
+    // hazards are stored in a multi-word bitmask.
+    println!("cargo:rerun-if-changed=wide.lock");
+    let producer = HazardsProducer::new().with_id_lock("wide.lock");
+    let ontology = producer.load(["../../ontology.jsonld"]).unwrap();
+
+    let out_dir = Path::new(&env::var_os("OUT_DIR").unwrap()).join("wide");
+    fs::create_dir_all(&out_dir).unwrap();
+    for file in producer.generate(&ontology, TemplateType::Rust).unwrap() {
+        fs::write(out_dir.join(file.path()), file.contents()).unwrap();
+    }
+}
@@ -0,0 +1,19 @@
+//! Hazards APIs generated at build time by the `hazard-generator`
+//! build script helpers.
+
+/// Maximum number of elements in a `HazardsData` collection.
+pub const MAXIMUM_ELEMENTS: usize = 32;
+
+// The generated APIs implement `PartialEq` on top of a derived `Hash`.
+#[allow(clippy::derived_hash_with_manual_eq)]
+mod hazards {
+    include!(concat!(env!("OUT_DIR"), "/ontology.rs"));
+}
+
+pub use hazards::*;
+
+/// Hazards APIs with identifiers above 64.
+#[allow(clippy::derived_hash_with_manual_eq)]
+pub mod wide {
+    include!(concat!(env!("OUT_DIR"), "/wide/ontology.rs"));
+}
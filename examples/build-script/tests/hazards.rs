@@ -0,0 +1,181 @@
+use build_script::{Category, Hazard, Hazards, HazardsData};
+
+#[test]
+fn generated_api() {
+    let hazard = Hazard::FireHazard;
+
+    assert_eq!(hazard.category(), Category::Safety);
+    assert_eq!(Hazard::from_id(hazard.id()), Some(hazard));
+
+    let mut hazards = Hazards::init();
+    hazards.add(hazard);
+    assert!(hazards.contains(hazard));
+}
+
+#[test]
+fn hazards_bitmask() {
+    let mut fire = Hazards::init();
+    fire.add(Hazard::FireHazard);
+    let mut fire_and_explosion = fire;
+    fire_and_explosion.add(Hazard::Explosion);
+
+    assert_eq!(fire_and_explosion.len(), 2);
+    assert!(fire.is_subset(&fire_and_explosion));
+    assert!(!fire_and_explosion.is_subset(&fire));
+    assert_eq!(fire.union(fire_and_explosion), fire_and_explosion);
+    assert_eq!(fire.intersection(fire_and_explosion), fire);
+    assert!(fire.difference(fire_and_explosion).is_empty());
+
+    // Hazards are iterated by identifier.
+    let mut iter = fire_and_explosion.iter();
+    assert_eq!(iter.next(), Some(Hazard::Explosion));
+    assert_eq!(iter.next(), Some(Hazard::FireHazard));
+    assert_eq!(iter.next(), None);
+
+    // Bits which do not correspond to any hazard are ignored.
+    let bits = u64::from(fire_and_explosion);
+    assert_eq!(Hazards::from(bits | 1 << 63), fire_and_explosion);
+    assert_eq!(u64::from(Hazards::from(bits)), bits);
+}
+
+#[test]
+fn hazards_set_algebra() {
+    let mut hazards: Hazards = [
+        Hazard::FireHazard,
+        Hazard::Explosion,
+        Hazard::GasConsumption,
+    ]
+    .into_iter()
+    .collect();
+
+    assert!(hazards.remove(Hazard::GasConsumption));
+    assert!(!hazards.remove(Hazard::GasConsumption));
+    assert_eq!(hazards.len(), 2);
+
+    let mut extended = hazards;
+    extended.extend([Hazard::GasConsumption]);
+    assert!(extended.is_superset(&hazards));
+    assert_eq!(extended.filter_by_category(Category::Safety), hazards);
+
+    let iterated: Vec<Hazard> = (&hazards).into_iter().collect();
+    assert_eq!(iterated, [Hazard::Explosion, Hazard::FireHazard]);
+}
+
+#[test]
+fn hazards_data_set_algebra() {
+    let hazards: Hazards = [Hazard::FireHazard, Hazard::GasConsumption]
+        .into_iter()
+        .collect();
+    let all = HazardsData::from_hazards(&hazards);
+    let safety = all.filter_by_category(Category::Safety);
+
+    assert_eq!(all.len(), 2);
+    assert_eq!(safety.len(), 1);
+    assert!(safety.is_subset(&all));
+    assert!(all.is_superset(&safety));
+    assert_eq!(all.intersection(&safety), safety);
+    assert_eq!(safety.union(&all), all);
+
+    let mut difference = all.difference(&safety);
+    assert_eq!(difference.len(), 1);
+    assert!((&difference)
+        .into_iter()
+        .all(|hazard_data| hazard_data.id == Hazard::GasConsumption.id()));
+
+    difference.merge(&safety);
+    assert_eq!(difference, all);
+
+    let hazard_data = safety.iter().next().unwrap().clone();
+    assert!(difference.remove(&hazard_data));
+    let collected: HazardsData = difference.iter().cloned().collect();
+    assert_eq!(collected.len(), 1);
+}
+
+#[test]
+fn parse_hazards() {
+    for value in [
+        "FireHazard",
+        "Fire Hazard",
+        "https://purl.org/sifis/hazards#FireHazard",
+    ] {
+        assert_eq!(value.parse(), Ok(Hazard::FireHazard));
+    }
+    assert_eq!("Safety".parse(), Ok(Category::Safety));
+    assert!("Fire".parse::<Hazard>().is_err());
+
+    let id = Hazard::FireHazard.id();
+    assert_eq!(Hazard::try_from(id), Ok(Hazard::FireHazard));
+    assert!(Hazard::try_from(u16::MAX).is_err());
+}
+
+#[test]
+fn serde_labels() {
+    let json = serde_json::to_string(&[Hazard::FireHazard, Hazard::Explosion]).unwrap();
+    assert_eq!(json, r#"["FireHazard","Explosion"]"#);
+
+    let hazards: Vec<Hazard> = serde_json::from_str(&json).unwrap();
+    assert_eq!(hazards, [Hazard::FireHazard, Hazard::Explosion]);
+
+    let category: Category = serde_json::from_str(r#""Safety""#).unwrap();
+    assert_eq!(category, Category::Safety);
+    assert!(serde_json::from_str::<Hazard>(r#""Fire""#).is_err());
+}
+
+// Lookup table built at compile time from the generated constants.
+const RISKY_CATEGORIES: [bool; Category::COUNT] = {
+    let mut table = [false; Category::COUNT];
+    let mut i = 0;
+    while i < Hazard::COUNT {
+        let category = Hazard::ALL[i].category();
+        let mut j = 0;
+        while j < Category::COUNT {
+            if Category::ALL[j] as usize == category as usize {
+                table[j] = true;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    table
+};
+
+#[test]
+fn enumerate_hazards() {
+    assert_eq!(Hazard::ALL.len(), Hazard::COUNT);
+    for hazard in Hazard::ALL {
+        assert_eq!(Hazard::from_id(hazard.id()), Some(hazard));
+    }
+
+    const SAFETY: Option<Category> = Category::from_name("Safety");
+    assert_eq!(SAFETY, Some(Category::Safety));
+    assert_eq!(Category::from_name("Unknown"), None);
+    for category in Category::ALL {
+        assert_eq!(Category::from_name(category.name()), Some(category));
+    }
+
+    assert!(RISKY_CATEGORIES.iter().all(|risky| *risky));
+}
+
+#[test]
+fn wide_hazards_bitmask() {
+    use build_script::wide;
+
+    assert_eq!(wide::Hazard::FireHazard.id(), 106);
+    assert_eq!(wide::Hazards::default(), wide::Hazards::init());
+
+    let mut hazards = wide::Hazards::init();
+    hazards.add(wide::Hazard::FireHazard);
+    hazards.add(wide::Hazard::WaterFlooding);
+
+    let words: [u64; 2] = hazards.into();
+    assert_eq!(words, [0, (1 << (106 - 64)) | (1 << (121 - 64))]);
+    assert_eq!(wide::Hazards::from(words), hazards);
+
+    // Bits which are not hazard identifiers are ignored.
+    assert_eq!(wide::Hazards::from([u64::MAX, 0]), wide::Hazards::init());
+    assert_eq!(wide::Hazards::from(u64::MAX), wide::Hazards::init());
+
+    // Hazards with an identifier greater than 63 do not fit in a `u64`.
+    assert_eq!(u64::try_from(hazards), Err(wide::BitmaskOverflow));
+    assert_eq!(u64::try_from(wide::Hazards::init()), Ok(0));
+}
@@ -1,34 +1,122 @@
 use std::path::PathBuf;
 
 use clap::builder::{PossibleValuesParser, TypedValueParser};
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 
 use tracing_subscriber::EnvFilter;
 
-use hazard_generator::{HazardsProducer, TemplateType};
+use hazard_generator::{HazardsProducer, InputFormat, Profile, SerdeRepresentation, TemplateType};
+
+#[derive(Args, Debug)]
+struct OntologyArgs {
+    /// Path to an ontology file. Repeat it to merge several ontologies.
+    #[clap(short = 'p', required = true, value_hint = clap::ValueHint::FilePath)]
+    ontology_path: Vec<PathBuf>,
+    /// Format of the ontology file, detected from its extension if not set.
+    #[clap(long, value_parser = PossibleValuesParser::new(InputFormat::all())
+    .map(|s| s.parse::<InputFormat>().unwrap()))]
+    input_format: Option<InputFormat>,
+    /// Map a remote JSON-LD context IRI to a local file, as `iri=path`.
+    #[clap(long, value_parser = parse_context_map)]
+    context_map: Vec<(String, PathBuf)>,
+    /// Path to a directory containing cached remote JSON-LD contexts.
+    #[clap(long, value_hint = clap::ValueHint::DirPath)]
+    context_cache: Option<PathBuf>,
+}
+
+impl OntologyArgs {
+    fn producer(self) -> (HazardsProducer, Vec<PathBuf>) {
+        let mut producer = HazardsProducer::new();
+
+        if let Some(input_format) = self.input_format {
+            producer = producer.with_input_format(input_format);
+        }
+
+        for (iri, path) in self.context_map {
+            producer = producer.with_context(iri, path);
+        }
+
+        if let Some(context_cache) = self.context_cache {
+            producer = producer.with_context_cache(context_cache);
+        }
+
+        (producer, self.ontology_path)
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Validate the ontology and report its structural problems.
+    Validate {
+        #[clap(flatten)]
+        ontology: OntologyArgs,
+    },
+}
 
 #[derive(Parser, Debug)]
-struct Args {
-    /// Path to the ontology file.
-    #[clap(short = 'p', value_hint = clap::ValueHint::FilePath)]
-    ontology_path: PathBuf,
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct CliArgs {
+    #[clap(subcommand)]
+    command: Option<Command>,
+    #[clap(flatten)]
+    ontology: OntologyArgs,
     /// Name of a builtin template.
-    #[clap(long, short, value_parser = PossibleValuesParser::new(TemplateType::all())
+    #[clap(long, short, required = true, value_parser = PossibleValuesParser::new(TemplateType::all())
     .map(|s| s.parse::<TemplateType>().unwrap()))]
-    template: TemplateType,
+    template: Option<TemplateType>,
     /// Parse the risk score associated to the hazards.
     #[clap(long)]
     with_risk: bool,
+    /// Package of the generated APIs, for templates that support it.
+    #[clap(long)]
+    package: Option<String>,
+    /// Generate a complete Rust crate with the given name.
+    #[clap(long)]
+    rust_crate: Option<String>,
+    /// Profile of the generated Rust APIs.
+    #[clap(long, value_parser = PossibleValuesParser::new(Profile::all())
+    .map(|s| s.parse::<Profile>().unwrap()))]
+    rust_profile: Option<Profile>,
+    /// Serde representation of the generated Rust hazards and categories.
+    #[clap(long, value_parser = PossibleValuesParser::new(SerdeRepresentation::all())
+    .map(|s| s.parse::<SerdeRepresentation>().unwrap()))]
+    serde_representation: Option<SerdeRepresentation>,
+    /// Path to a directory containing templates that override the builtin ones.
+    #[clap(long, value_hint = clap::ValueHint::DirPath)]
+    template_dir: Option<PathBuf>,
+    /// Path to the lock file containing the identifiers of the hazards.
+    #[clap(long, value_hint = clap::ValueHint::FilePath)]
+    id_lock: Option<PathBuf>,
     /// Path to the output directory.
-    #[clap(short, value_hint = clap::ValueHint::DirPath)]
-    output_path: PathBuf,
+    #[clap(short, required = true, value_hint = clap::ValueHint::DirPath)]
+    output_path: Option<PathBuf>,
     /// Output the generated template paths as they are produced.
     #[clap(short, long)]
     verbose: bool,
 }
 
+fn parse_context_map(value: &str) -> Result<(String, PathBuf), String> {
+    value
+        .split_once('=')
+        .map(|(iri, path)| (iri.to_owned(), PathBuf::from(path)))
+        .ok_or_else(|| format!("{value:?} is not in the `iri=path` form"))
+}
+
+fn validate(ontology: OntologyArgs) {
+    let (producer, ontology_paths) = ontology.producer();
+
+    let issues = producer.validate(ontology_paths).unwrap();
+    for issue in &issues {
+        println!("{issue}");
+    }
+
+    if !issues.is_empty() {
+        std::process::exit(1);
+    }
+}
+
 fn main() {
-    let args = Args::parse();
+    let args = CliArgs::parse();
 
     // Enable filter to log the information contained in the lib.
     let filter_layer = EnvFilter::try_from_default_env()
@@ -48,9 +136,45 @@ fn main() {
         .with_writer(std::io::stderr)
         .init();
 
+    if let Some(Command::Validate { ontology }) = args.command {
+        validate(ontology);
+        return;
+    }
+
+    // Define HazardsProducer.
+    let (producer, ontology_paths) = args.ontology.producer();
+    let mut producer = producer.with_risk(args.with_risk);
+
+    if let Some(package) = args.package {
+        producer = producer.with_package(package);
+    }
+
+    if let Some(rust_crate) = args.rust_crate {
+        producer = producer.with_rust_crate(rust_crate);
+    }
+
+    if let Some(rust_profile) = args.rust_profile {
+        producer = producer.with_rust_profile(rust_profile);
+    }
+
+    if let Some(serde_representation) = args.serde_representation {
+        producer = producer.with_serde_representation(serde_representation);
+    }
+
+    if let Some(template_dir) = args.template_dir {
+        producer = producer.with_template_dir(template_dir);
+    }
+
+    if let Some(id_lock) = args.id_lock {
+        producer = producer.with_id_lock(id_lock);
+    }
+
     // Run HazardsProducer.
-    HazardsProducer::new()
-        .with_risk(args.with_risk)
-        .run(args.ontology_path, args.output_path, args.template)
+    producer
+        .run(
+            ontology_paths,
+            args.output_path.unwrap(),
+            args.template.unwrap(),
+        )
         .unwrap()
 }
@@ -0,0 +1,114 @@
+//! Helpers to generate the Rust hazards APIs from a build script.
+//!
+//! The APIs are generated into the `OUT_DIR` directory at each build,
+//! so the generated code never needs to be checked in:
+//!
+//! ```no_run
+//! // In the `main` function of build.rs
+//! hazard_generator::build::generate(["ontology.jsonld"]).unwrap();
+//! ```
+//!
+//! The generated file is then included in a module since, with the
+//! default [`Profile::NoStd`](crate::Profile::NoStd) profile, it refers to
+//! the `MAXIMUM_ELEMENTS` constant defined in the crate root:
+//!
+//! ```ignore
+//! // src/lib.rs
+//! pub const MAXIMUM_ELEMENTS: usize = 32;
+//!
+//! mod hazards {
+//!     include!(concat!(env!("OUT_DIR"), "/ontology.rs"));
+//! }
+//!
+//! pub use hazards::*;
+//! ```
+//!
+//! The `serde` implementations of the generated types are enabled by a
+//! `serde` feature of the including crate.
+//!
+//! When the risk score is enabled, the generated file is named
+//! `ontology_with_risk.rs`.
+
+use std::{
+    env,
+    fs::write,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::{Error, ErrorKind, Result},
+    HazardsProducer, TemplateType,
+};
+
+/// Generates the Rust hazards APIs into the `OUT_DIR` directory.
+///
+/// Several ontology files are merged as in [`HazardsProducer::run`].
+/// Returns the path of the generated file, to be used with `include!`.
+pub fn generate<P: AsRef<Path>>(ontology_paths: impl IntoIterator<Item = P>) -> Result<PathBuf> {
+    generate_with(&HazardsProducer::new(), ontology_paths)
+}
+
+/// Generates the Rust hazards APIs into the `OUT_DIR` directory,
+/// using the options of a [`HazardsProducer`].
+///
+/// Cargo is asked to run the build script again whenever an ontology
+/// file, the template directory, a local JSON-LD context or the
+/// identifiers lock file changes. When one of them does not exist yet,
+/// its parent directory is watched instead. The identifiers lock file
+/// is only read, so the build never writes into the source tree: update
+/// it with [`HazardsProducer::render`] or the command line tool.
+/// Returns the path of the generated file, to be used with `include!`.
+pub fn generate_with<P: AsRef<Path>>(
+    producer: &HazardsProducer,
+    ontology_paths: impl IntoIterator<Item = P>,
+) -> Result<PathBuf> {
+    let out_dir = env::var_os("OUT_DIR").map(PathBuf::from).ok_or_else(|| {
+        Error::new(
+            ErrorKind::PathFormat,
+            "OUT_DIR MUST be set, so the APIs can only be generated from a build script",
+        )
+    })?;
+
+    // A crate cannot be included in another one.
+    if producer.rust_crate.is_some() {
+        return Err(Error::new(
+            ErrorKind::Template,
+            "A Rust crate cannot be generated from a build script",
+        ));
+    }
+
+    // Track all the inputs of the generation.
+    let ontology_paths: Vec<P> = ontology_paths.into_iter().collect();
+    let inputs = ontology_paths
+        .iter()
+        .map(AsRef::as_ref)
+        .chain(producer.template_dir.as_deref())
+        .chain(producer.contexts.iter().map(|(_, path)| path.as_path()))
+        .chain(producer.context_cache.as_deref())
+        .chain(producer.id_lock.as_deref());
+    for input in inputs {
+        // Cargo always runs again the build script when a watched path does
+        // not exist, so watch the parent directory until the file is created.
+        let watched = if input.exists() {
+            input
+        } else {
+            match input.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            }
+        };
+        println!("cargo:rerun-if-changed={}", watched.display());
+    }
+
+    let ontology = producer.load(ontology_paths)?;
+    let files = producer.generate(&ontology, TemplateType::Rust)?;
+
+    let mut api_path = None;
+    for file in files {
+        let path = out_dir.join(file.path());
+        write(&path, file.contents())?;
+        api_path.get_or_insert(path);
+    }
+
+    api_path.ok_or_else(|| Error::new(ErrorKind::Template, "No Rust API has been generated"))
+}
@@ -1,9 +1,11 @@
 use std::borrow::Cow;
 
 use iref::IriBuf;
-use json_ld::{flattening::ConflictingIndexes, none::CannotLoad, ExpandError};
+use json_ld::{flattening::ConflictingIndexes, ExpandError};
 use rdf_types::BlankIdBuf;
 
+use crate::loader::LoadError;
+
 /// All possible error kinds.
 #[derive(Debug, Copy, Clone)]
 pub enum ErrorKind {
@@ -15,6 +17,12 @@ pub enum ErrorKind {
     Io,
     /// Template error.
     Template,
+    /// Hazard identifier error.
+    Identifier,
+    /// Ontology format error.
+    Format,
+    /// Ontology merge error.
+    Merge,
 }
 
 impl ErrorKind {
@@ -24,6 +32,9 @@ impl ErrorKind {
             ErrorKind::PathFormat => "Path format error",
             ErrorKind::Io => "I/O error",
             ErrorKind::Template => "Template error",
+            ErrorKind::Identifier => "Identifier error",
+            ErrorKind::Format => "Ontology format error",
+            ErrorKind::Merge => "Ontology merge error",
         }
     }
 }
@@ -68,7 +79,7 @@ impl From<std::io::Error> for Error {
 
 impl From<minijinja::Error> for Error {
     fn from(e: minijinja::Error) -> Self {
-        Self::new(ErrorKind::Io, e.to_string())
+        Self::new(ErrorKind::Template, e.to_string())
     }
 }
 
@@ -78,8 +89,8 @@ impl From<ConflictingIndexes<IriBuf, BlankIdBuf>> for Error {
     }
 }
 
-impl From<ExpandError<CannotLoad<IriBuf>>> for Error {
-    fn from(e: ExpandError<CannotLoad<IriBuf>>) -> Self {
+impl From<ExpandError<LoadError>> for Error {
+    fn from(e: ExpandError<LoadError>) -> Self {
         Self::new(ErrorKind::JsonLd, e.to_string())
     }
 }
@@ -90,5 +101,17 @@ impl From<json_ld::syntax::parse::Error> for Error {
     }
 }
 
+impl From<oxttl::TurtleSyntaxError> for Error {
+    fn from(e: oxttl::TurtleSyntaxError) -> Self {
+        Self::new(ErrorKind::Format, e.to_string())
+    }
+}
+
+impl From<oxrdfxml::RdfXmlSyntaxError> for Error {
+    fn from(e: oxrdfxml::RdfXmlSyntaxError) -> Self {
+        Self::new(ErrorKind::Format, e.to_string())
+    }
+}
+
 /// A specialized `Result` type.
 pub type Result<T> = std::result::Result<T, Error>;
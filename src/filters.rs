@@ -3,15 +3,34 @@ use minijinja::{Error, State};
 // Converts a camelcase string to a string with words separated by whitespaces.
 // For example, "ElectricEnergyConsumption" becomes "Electric Energy Consumption".
 pub(crate) fn camelcase_to_whitespaces(_state: &State, value: String) -> Result<String, Error> {
-    Ok(value
+    Ok(split_camelcase(&value, ' '))
+}
+
+// Converts a camelcase string to a snakecase string.
+// For example, "ElectricEnergyConsumption" becomes "electric_energy_consumption".
+pub(crate) fn camelcase_to_snakecase(_state: &State, value: String) -> Result<String, Error> {
+    Ok(split_camelcase(&value, '_').to_lowercase())
+}
+
+// Inserts a separator before each uppercase character of a camelcase string,
+// except for the first one.
+fn split_camelcase(value: &str, separator: char) -> String {
+    value
         .chars()
         .enumerate()
         .fold(String::new(), |mut result, (i, c)| {
-            // Insert a space if the current character is not uppercase and is not the first one.
+            // Insert a separator if the current character is uppercase and is not the first one.
             if c.is_uppercase() && i != 0 {
-                result.push(' ');
+                result.push(separator);
             }
             result.push(c);
             result
-        }))
+        })
+}
+
+// Rounds a number up to the next power of two, as required by the capacity
+// of `heapless` sets. The result is at least 2, the smallest valid capacity.
+// For example, 22 becomes 32.
+pub(crate) fn next_power_of_two(_state: &State, value: usize) -> Result<usize, Error> {
+    Ok(value.next_power_of_two().max(2))
 }
@@ -0,0 +1,261 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{read_to_string, write},
+    path::Path,
+};
+
+use crate::error::{Error, ErrorKind, Result};
+
+const LOCK_HEADER: &str = "\
+# Hazard identifiers generated by hazard-generator.
+# Each line contains an identifier and the IRI of the hazard it belongs to.
+# Retired identifiers are marked with `retired` and are never reused.
+";
+
+const RETIRED: &str = "retired";
+
+// An entry of the identifiers lock file.
+struct LockEntry {
+    id: u16,
+    iri: String,
+    retired: bool,
+}
+
+// Identifiers lock file, which maps hazard IRIs to their identifiers.
+#[derive(Default)]
+pub(crate) struct IdLock {
+    entries: Vec<LockEntry>,
+}
+
+impl IdLock {
+    // Reads a lock file.
+    //
+    // A missing lock file is considered empty, so that it can be created
+    // the first time the generator runs.
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let mut entries = Vec::new();
+        let mut ids = HashSet::new();
+        let mut iris = HashSet::new();
+
+        for (number, line) in read_to_string(path)?.lines().enumerate() {
+            let line = line.trim();
+
+            // Skip empty lines and comments.
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = Self::parse_line(line).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Identifier,
+                    format!("Malformed lock file entry at line {}: {line:?}", number + 1),
+                )
+            })?;
+
+            if !ids.insert(entry.id) {
+                return Err(Error::new(
+                    ErrorKind::Identifier,
+                    format!(
+                        "Identifier {} is used more than once in the lock file",
+                        entry.id
+                    ),
+                ));
+            }
+            if !iris.insert(entry.iri.clone()) {
+                return Err(Error::new(
+                    ErrorKind::Identifier,
+                    format!(
+                        "Hazard {} is listed more than once in the lock file",
+                        entry.iri
+                    ),
+                ));
+            }
+
+            entries.push(entry);
+        }
+
+        Ok(Self { entries })
+    }
+
+    fn parse_line(line: &str) -> Option<LockEntry> {
+        let mut fields = line.split_whitespace();
+
+        let id = fields.next()?.parse().ok()?;
+        let iri = fields.next()?.to_owned();
+        let retired = match fields.next() {
+            Some(RETIRED) => true,
+            Some(_) => return None,
+            None => false,
+        };
+
+        // No other fields are allowed.
+        if fields.next().is_some() {
+            return None;
+        }
+
+        Some(LockEntry { id, iri, retired })
+    }
+
+    // Writes the lock file.
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        let mut lock = String::from(LOCK_HEADER);
+
+        for entry in &self.entries {
+            lock.push_str(&format!("{} {}", entry.id, entry.iri));
+            if entry.retired {
+                lock.push(' ');
+                lock.push_str(RETIRED);
+            }
+            lock.push('\n');
+        }
+
+        write(path, lock)?;
+
+        Ok(())
+    }
+}
+
+// Identifiers assigned to the hazards of an ontology.
+#[derive(Default)]
+pub(crate) struct Identifiers {
+    ids: HashMap<String, u16>,
+    retired: Vec<u16>,
+}
+
+impl Identifiers {
+    // Assigns an identifier to each hazard, given its IRI and
+    // its explicit identifier, if any.
+    //
+    // Explicit identifiers come first, then identifiers contained in the
+    // lock file. The remaining hazards obtain fresh identifiers, following
+    // the order in which they are given.
+    //
+    // Returns the assigned identifiers and the updated lock file.
+    pub(crate) fn assign<'a>(
+        hazards: impl IntoIterator<Item = (&'a str, Option<u16>)>,
+        lock: &IdLock,
+    ) -> Result<(Self, IdLock)> {
+        let hazards: Vec<_> = hazards.into_iter().collect();
+
+        let locked: HashMap<&str, u16> = lock
+            .entries
+            .iter()
+            .map(|entry| (entry.iri.as_str(), entry.id))
+            .collect();
+        let owners: HashMap<u16, &str> = lock
+            .entries
+            .iter()
+            .map(|entry| (entry.id, entry.iri.as_str()))
+            .collect();
+
+        let mut ids = HashMap::new();
+        let mut assigned = HashMap::new();
+
+        // Assign explicit identifiers.
+        for (iri, id) in hazards
+            .iter()
+            .filter_map(|(iri, id)| id.map(|id| (*iri, id)))
+        {
+            if let Some(locked_id) = locked.get(iri).filter(|locked_id| **locked_id != id) {
+                return Err(Error::new(
+                    ErrorKind::Identifier,
+                    format!(
+                        "Hazard {iri} has identifier {id}, but the lock file assigns {locked_id}"
+                    ),
+                ));
+            }
+            if let Some(owner) = owners.get(&id).filter(|owner| **owner != iri) {
+                return Err(Error::new(
+                    ErrorKind::Identifier,
+                    format!("Identifier {id} of hazard {iri} is already assigned to {owner}"),
+                ));
+            }
+            if let Some(other) = assigned.insert(id, iri) {
+                return Err(Error::new(
+                    ErrorKind::Identifier,
+                    format!("Identifier {id} is assigned to both {other} and {iri}"),
+                ));
+            }
+            ids.insert(iri.to_owned(), id);
+        }
+
+        // Assign locked identifiers.
+        for (iri, _) in hazards.iter().filter(|(_, id)| id.is_none()) {
+            if let Some(id) = locked.get(iri) {
+                if let Some(other) = assigned.insert(*id, iri) {
+                    return Err(Error::new(
+                        ErrorKind::Identifier,
+                        format!("Identifier {id} is assigned to both {other} and {iri}"),
+                    ));
+                }
+                ids.insert(iri.to_string(), *id);
+            }
+        }
+
+        // Assign fresh identifiers, never reusing identifiers contained
+        // in the lock file, even the retired ones.
+        let mut next_id = owners
+            .keys()
+            .chain(assigned.keys())
+            .max()
+            .map_or(Some(0), |id| id.checked_add(1));
+        for (iri, _) in &hazards {
+            if ids.contains_key(*iri) {
+                continue;
+            }
+            let id = next_id.ok_or_else(|| {
+                Error::new(ErrorKind::Identifier, "No more identifiers are available")
+            })?;
+            ids.insert(iri.to_string(), id);
+            next_id = id.checked_add(1);
+        }
+
+        // Retire the identifiers of the hazards that do not exist anymore.
+        let mut retired: Vec<u16> = lock
+            .entries
+            .iter()
+            .filter(|entry| !ids.contains_key(&entry.iri))
+            .map(|entry| entry.id)
+            .collect();
+        retired.sort_unstable();
+
+        // Update the lock file.
+        let mut entries: Vec<LockEntry> = ids
+            .iter()
+            .map(|(iri, id)| LockEntry {
+                id: *id,
+                iri: iri.clone(),
+                retired: false,
+            })
+            .chain(
+                lock.entries
+                    .iter()
+                    .filter(|entry| !ids.contains_key(&entry.iri))
+                    .map(|entry| LockEntry {
+                        id: entry.id,
+                        iri: entry.iri.clone(),
+                        retired: true,
+                    }),
+            )
+            .collect();
+        entries.sort_unstable_by_key(|entry| entry.id);
+
+        Ok((Self { ids, retired }, IdLock { entries }))
+    }
+
+    // Returns the identifier assigned to a hazard.
+    #[inline(always)]
+    pub(crate) fn id(&self, iri: &str) -> u16 {
+        self.ids[iri]
+    }
+
+    // Returns the retired identifiers.
+    #[inline(always)]
+    pub(crate) fn retired(&self) -> &[u16] {
+        &self.retired
+    }
+}
@@ -5,26 +5,52 @@
 //! for the following programming languages:
 //!
 //! - Rust
+//! - C
+//! - C++
+//! - Python
+//! - TypeScript
+//! - Go
+//! - Java
+//! - Protocol Buffers
+//!
+//! It can also generate the Markdown documentation of the hazards
+//! and a JSON Schema to validate hazards data.
 
+pub mod build;
 mod error;
 mod filters;
+mod identifiers;
+mod loader;
+mod merge;
+mod model;
+mod rdf;
 mod toolchain;
+mod validation;
+
+pub use model::{Category, Hazard, HazardOntology, OntologyMetadata, RiskScoreRange};
+pub use validation::{IssueKind, ValidationIssue};
 
 use std::{
     collections::HashMap,
-    fs::{read_to_string, write},
-    path::{Path, PathBuf},
+    fs::{create_dir_all, read_to_string, write},
+    io::Write,
+    path::{Component, Path, PathBuf},
     str::FromStr,
 };
 
 use error::{Error, ErrorKind, Result};
-use filters::camelcase_to_whitespaces;
+use filters::{camelcase_to_snakecase, camelcase_to_whitespaces, next_power_of_two};
 use futures::executor;
+use identifiers::{IdLock, Identifiers};
+use iref::IriBuf;
 use json_ld::{syntax::Parse, Flatten, Indexed, JsonLdProcessor, Node, RemoteDocument};
+use loader::LocalLoader;
+use merge::merge;
 use minijinja::{Environment, Value};
 use toolchain::{
-    context, create_hazard, description, is_category, is_hazard, name, object_type, rust::Rust,
-    CategoryData,
+    c::C, context, cpp::Cpp, go::Go, java::Java, json_schema::JsonSchema, markdown::Markdown,
+    protobuf::Protobuf, python::Python, rust::Rust, typescript::TypeScript, CategoryData,
+    HazardData,
 };
 
 /// Supported templates.
@@ -32,12 +58,41 @@ use toolchain::{
 pub enum TemplateType {
     /// Generate hazards APIs for Rust.
     Rust,
+    /// Generate hazards APIs for C.
+    C,
+    /// Generate hazards APIs for C++.
+    Cpp,
+    /// Generate hazards APIs for Python.
+    Python,
+    /// Generate hazards APIs for TypeScript.
+    TypeScript,
+    /// Generate hazards APIs for Go.
+    Go,
+    /// Generate hazards APIs for Java.
+    Java,
+    /// Generate hazards documentation in Markdown.
+    Markdown,
+    /// Generate a JSON Schema to validate hazards data.
+    JsonSchema,
+    /// Generate hazards messages for Protocol Buffers.
+    Protobuf,
 }
 
 impl TemplateType {
     /// All `TemplateType` options.
     pub const fn all() -> &'static [&'static str] {
-        &["rust"]
+        &[
+            "rust",
+            "c",
+            "cpp",
+            "python",
+            "typescript",
+            "go",
+            "java",
+            "markdown",
+            "json-schema",
+            "protobuf",
+        ]
     }
 }
 
@@ -47,6 +102,15 @@ impl FromStr for TemplateType {
     fn from_str(template: &str) -> std::result::Result<Self, Self::Err> {
         match template {
             "rust" => Ok(Self::Rust),
+            "c" => Ok(Self::C),
+            "cpp" => Ok(Self::Cpp),
+            "python" => Ok(Self::Python),
+            "typescript" => Ok(Self::TypeScript),
+            "go" => Ok(Self::Go),
+            "java" => Ok(Self::Java),
+            "markdown" => Ok(Self::Markdown),
+            "json-schema" => Ok(Self::JsonSchema),
+            "protobuf" => Ok(Self::Protobuf),
             template => Err(Error::new(
                 ErrorKind::Template,
                 format!("{template:?} is not a supported template."),
@@ -55,13 +119,199 @@ impl FromStr for TemplateType {
     }
 }
 
-struct OutputFile {
+/// Profiles of the generated Rust APIs.
+///
+/// A profile defines the collection used to store sets of hazard data,
+/// that is `HazardsData`.
+///
+/// Sets of hazards, that is `Hazards`, are always stored as a bitmask
+/// of their identifiers, whatever the profile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Profile {
+    /// Fixed-capacity `heapless::FnvIndexSet`, for `no_std` targets
+    /// without an allocator.
+    ///
+    /// The capacity is the `MAXIMUM_ELEMENTS` constant, which must be
+    /// defined in the crate root.
+    #[default]
+    NoStd,
+    /// `alloc::collections::BTreeSet`, for `no_std` targets with
+    /// an allocator.
+    ///
+    /// The `alloc` crate must be declared in the crate root
+    /// with `extern crate alloc;`.
+    Alloc,
+    /// `std::collections::HashSet`, for targets with the standard library.
+    Std,
+}
+
+impl Profile {
+    /// All `Profile` options.
+    pub const fn all() -> &'static [&'static str] {
+        &["no-std", "alloc", "std"]
+    }
+
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::NoStd => "no-std",
+            Self::Alloc => "alloc",
+            Self::Std => "std",
+        }
+    }
+}
+
+impl FromStr for Profile {
+    type Err = Error;
+
+    fn from_str(profile: &str) -> std::result::Result<Self, Self::Err> {
+        match profile {
+            "no-std" => Ok(Self::NoStd),
+            "alloc" => Ok(Self::Alloc),
+            "std" => Ok(Self::Std),
+            profile => Err(Error::new(
+                ErrorKind::Template,
+                format!("{profile:?} is not a supported Rust profile."),
+            )),
+        }
+    }
+}
+
+/// Serde representations of the generated Rust hazards and categories.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SerdeRepresentation {
+    /// Hazards are represented by their identifier.
+    ///
+    /// Categories do not have an identifier, so they are represented
+    /// by their label.
+    Id,
+    /// Hazards and categories are represented by their label,
+    /// such as `FireHazard`.
+    #[default]
+    Label,
+    /// Hazards and categories are represented by their IRI.
+    Iri,
+}
+
+impl SerdeRepresentation {
+    /// All `SerdeRepresentation` options.
+    pub const fn all() -> &'static [&'static str] {
+        &["id", "label", "iri"]
+    }
+
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Label => "label",
+            Self::Iri => "iri",
+        }
+    }
+}
+
+impl FromStr for SerdeRepresentation {
+    type Err = Error;
+
+    fn from_str(representation: &str) -> std::result::Result<Self, Self::Err> {
+        match representation {
+            "id" => Ok(Self::Id),
+            "label" => Ok(Self::Label),
+            "iri" => Ok(Self::Iri),
+            representation => Err(Error::new(
+                ErrorKind::Template,
+                format!("{representation:?} is not a supported serde representation."),
+            )),
+        }
+    }
+}
+
+/// Supported ontology formats.
+#[derive(Debug, Clone, Copy)]
+pub enum InputFormat {
+    /// JSON-LD ontology.
+    JsonLd,
+    /// Turtle ontology.
+    Turtle,
+    /// N-Triples ontology.
+    NTriples,
+    /// N-Quads ontology.
+    NQuads,
+    /// RDF/XML ontology.
+    RdfXml,
+}
+
+impl InputFormat {
+    /// All `InputFormat` options.
+    pub const fn all() -> &'static [&'static str] {
+        &["json-ld", "turtle", "n-triples", "n-quads", "rdf-xml"]
+    }
+
+    /// Detects the format of an ontology file from its extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "jsonld" | "json" => Some(Self::JsonLd),
+            "ttl" => Some(Self::Turtle),
+            "nt" => Some(Self::NTriples),
+            "nq" => Some(Self::NQuads),
+            "owl" | "rdf" => Some(Self::RdfXml),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for InputFormat {
+    type Err = Error;
+
+    fn from_str(input_format: &str) -> std::result::Result<Self, Self::Err> {
+        match input_format {
+            "json-ld" => Ok(Self::JsonLd),
+            "turtle" => Ok(Self::Turtle),
+            "n-triples" => Ok(Self::NTriples),
+            "n-quads" => Ok(Self::NQuads),
+            "rdf-xml" => Ok(Self::RdfXml),
+            input_format => Err(Error::new(
+                ErrorKind::Format,
+                format!("{input_format:?} is not a supported input format."),
+            )),
+        }
+    }
+}
+
+/// A file generated from a hazard ontology.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
     path: PathBuf,
+    contents: String,
+}
+
+impl GeneratedFile {
+    /// Returns the path of the file, relative to the output directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the contents of the file.
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+
+    /// Consumes the file, returning its contents.
+    pub fn into_contents(self) -> String {
+        self.contents
+    }
+
+    /// Writes the contents of the file to a writer.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<()> {
+        writer.write_all(self.contents.as_bytes())?;
+        Ok(())
+    }
+}
+
+struct OutputFile {
+    path: &'static str,
     template_name: &'static str,
 }
 
 impl OutputFile {
-    pub(crate) const fn new(path: PathBuf, template_name: &'static str) -> Self {
+    pub(crate) const fn new(path: &'static str, template_name: &'static str) -> Self {
         OutputFile {
             path,
             template_name,
@@ -71,49 +321,56 @@ impl OutputFile {
 
 struct Output {
     context: HashMap<&'static str, Value>,
-    file: OutputFile,
+    files: Vec<OutputFile>,
 }
 
 impl Output {
-    pub(crate) const fn new(context: HashMap<&'static str, Value>, file: OutputFile) -> Self {
-        Output { context, file }
+    pub(crate) const fn new(context: HashMap<&'static str, Value>, files: Vec<OutputFile>) -> Self {
+        Output { context, files }
     }
 }
 
 struct Template {
     context: HashMap<&'static str, Value>,
-    output_file: OutputFile,
+    output_files: Vec<OutputFile>,
     env: Environment<'static>,
 }
 
 impl Template {
     const fn new(
         context: HashMap<&'static str, Value>,
-        output_file: OutputFile,
+        output_files: Vec<OutputFile>,
         env: Environment<'static>,
     ) -> Self {
         Self {
             context,
-            output_file,
+            output_files,
             env,
         }
     }
 
-    fn render(self) -> Result<()> {
+    fn render(self) -> Result<Vec<GeneratedFile>> {
         let Template {
             context,
-            output_file,
+            output_files,
             mut env,
         } = self;
 
         env.add_filter("camelcase_to_whitespaces", camelcase_to_whitespaces);
+        env.add_filter("camelcase_to_snakecase", camelcase_to_snakecase);
+        env.add_filter("next_power_of_two", next_power_of_two);
 
         // Fill in the templates.
-        let template = env.get_template(output_file.template_name)?;
-        let filled_template = template.render(&context)?;
-        write(output_file.path, filled_template)?;
-
-        Ok(())
+        output_files
+            .into_iter()
+            .map(|output_file| {
+                let template = env.get_template(output_file.template_name)?;
+                Ok(GeneratedFile {
+                    path: PathBuf::from(output_file.path),
+                    contents: template.render(&context)?,
+                })
+            })
+            .collect()
     }
 }
 
@@ -121,82 +378,169 @@ impl Template {
 trait BuildTemplate {
     fn get_templates() -> &'static [(&'static str, &'static str)];
 
-    fn output_file(output_path: &Path, with_risk: bool) -> OutputFile;
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)];
+
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile>;
 
-    fn define(&self, ontology: Ontology, output_path: &Path, with_risk: bool) -> Output {
-        let mut hazards = Vec::new();
-        let mut categories = Vec::new();
+    fn extend_context(&self, _context: &mut HashMap<&'static str, Value>) {}
+
+    fn define(
+        &self,
+        ontology: &HazardOntology,
+        with_risk: bool,
+        identifiers: &Identifiers,
+    ) -> Output {
         let mut categories_hazards: HashMap<&str, Vec<&str>> = HashMap::new();
 
-        ontology.iter().for_each(|object| {
-            if let Some(object_type) = object_type(object) {
-                let name = name(object);
-                let description = description(object);
-
-                if is_hazard(object_type) {
-                    // Create a new hazard.
-                    let hazard = create_hazard(object, name, description, with_risk);
-
-                    // Map hazard to its category.
-                    categories_hazards
-                        .entry(hazard.category)
-                        .or_default()
-                        .push(name);
-
-                    hazards.push(hazard);
-                } else if is_category(object_type) {
-                    categories.push(CategoryData::new(name, description, Vec::new()))
-                }
-            }
-        });
+        let hazards = ontology
+            .hazards
+            .iter()
+            .map(|hazard| {
+                // Map hazard to its category.
+                categories_hazards
+                    .entry(hazard.category_name())
+                    .or_default()
+                    .push(&hazard.label);
+
+                HazardData::new(
+                    identifiers.id(&hazard.iri),
+                    &hazard.iri,
+                    &hazard.label,
+                    &hazard.description,
+                    hazard.category_name(),
+                    hazard.risk_score.filter(|_| with_risk),
+                )
+            })
+            .collect();
 
         // Set corresponding hazards for each category.
-        categories.iter_mut().for_each(|category| {
-            category.hazards = categories_hazards
-                .get(category.name)
-                .map(|hazards| hazards.to_owned())
-                .unwrap_or_default();
-        });
+        let categories = ontology
+            .categories
+            .iter()
+            .map(|category| {
+                let hazards = categories_hazards
+                    .get(category.label.as_str())
+                    .map(|hazards| hazards.to_owned())
+                    .unwrap_or_default();
+
+                CategoryData::new(
+                    &category.iri,
+                    &category.label,
+                    &category.description,
+                    hazards,
+                )
+            })
+            .collect();
 
         // Define context.
-        let context = context(hazards, categories);
+        let mut context = context(
+            hazards,
+            categories,
+            ontology.risk_score_range,
+            identifiers.retired(),
+        );
 
-        // Define output file of the API.
-        let output_file = Self::output_file(output_path, with_risk);
+        // Add template specific data to the context.
+        self.extend_context(&mut context);
 
-        Output::new(context, output_file)
+        // Define output files of the API.
+        let output_files = self.output_files(with_risk);
+
+        Output::new(context, output_files)
     }
 
-    fn build(&self, ontology: Ontology, output_path: &Path, with_risk: bool) -> Template {
-        let output = self.define(ontology, output_path, with_risk);
-        let env = build_environment(Self::get_templates(), with_risk);
+    fn build(
+        &self,
+        ontology: &HazardOntology,
+        with_risk: bool,
+        template_dir: Option<&Path>,
+        identifiers: &Identifiers,
+    ) -> Template {
+        let output = self.define(ontology, with_risk, identifiers);
+        let env = build_environment(
+            Self::get_templates(),
+            Self::get_risk_templates(),
+            with_risk,
+            template_dir,
+        );
 
-        Template::new(output.context, output.file, env)
+        Template::new(output.context, output.files, env)
     }
 }
 
+// Prefix to refer to a builtin template even when a template
+// with the same name exists in the template directory.
+const BUILTIN_PREFIX: &str = "builtin/";
+
 fn build_environment(
     templates: &'static [(&'static str, &'static str)],
+    risk_templates: &'static [(&'static str, &'static str)],
     with_risk: bool,
+    template_dir: Option<&Path>,
 ) -> Environment<'static> {
     let mut environment = Environment::new();
 
-    // Add base ontology template to the `Environment`.
-    add_template(&mut environment, templates[0]);
+    // Collect base templates.
+    let mut builtin_templates = templates.to_vec();
 
-    // Add risk template to the `Environment`.
+    // Collect risk templates.
     if with_risk {
-        add_template(&mut environment, templates[1]);
+        builtin_templates.extend_from_slice(risk_templates);
     }
 
+    // Load templates lazily, looking for them in the template directory first
+    // and then among the builtin ones.
+    let template_dir = template_dir.map(Path::to_path_buf);
+    environment.set_loader(move |name| {
+        if let Some(builtin_name) = name.strip_prefix(BUILTIN_PREFIX) {
+            return Ok(builtin_template(&builtin_templates, builtin_name));
+        }
+
+        if let Some(template) = template_dir
+            .as_deref()
+            .map(|template_dir| load_template(template_dir, name))
+            .transpose()?
+            .flatten()
+        {
+            return Ok(Some(template));
+        }
+
+        Ok(builtin_template(&builtin_templates, name))
+    });
+
     environment
 }
 
-fn add_template(environment: &mut Environment, template: (&'static str, &'static str)) {
-    let (name, src) = template;
-    environment
-        .add_template(name, src)
-        .expect("Internal error, built-in template");
+fn builtin_template(templates: &[(&'static str, &'static str)], name: &str) -> Option<String> {
+    templates
+        .iter()
+        .find(|(template_name, _)| *template_name == name)
+        .map(|(_, src)| src.to_string())
+}
+
+fn load_template(
+    template_dir: &Path,
+    name: &str,
+) -> std::result::Result<Option<String>, minijinja::Error> {
+    // Do not allow templates outside the template directory.
+    if Path::new(name)
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_)))
+    {
+        return Ok(None);
+    }
+
+    let path = template_dir.join(name);
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    read_to_string(&path).map(Some).map_err(|e| {
+        minijinja::Error::new(
+            minijinja::ErrorKind::InvalidOperation,
+            format!("cannot read template {}: {e}", path.display()),
+        )
+    })
 }
 
 pub(crate) type Ontology = Vec<Indexed<Node>>;
@@ -205,6 +549,15 @@ pub(crate) type Ontology = Vec<Indexed<Node>>;
 #[derive(Default)]
 pub struct HazardsProducer {
     with_risk: bool,
+    package: Option<String>,
+    rust_crate: Option<String>,
+    rust_profile: Profile,
+    serde_representation: SerdeRepresentation,
+    template_dir: Option<PathBuf>,
+    id_lock: Option<PathBuf>,
+    input_format: Option<InputFormat>,
+    contexts: Vec<(String, PathBuf)>,
+    context_cache: Option<PathBuf>,
 }
 
 impl HazardsProducer {
@@ -220,26 +573,284 @@ impl HazardsProducer {
         self
     }
 
+    /// Sets the package of the generated APIs.
+    ///
+    /// Only used by templates whose language has the concept of package,
+    /// such as Java.
+    pub fn with_package(mut self, package: impl Into<String>) -> Self {
+        self.package = Some(package.into());
+        self
+    }
+
+    /// Generates a complete Rust crate with the given name, instead of
+    /// a lone Rust source file.
+    ///
+    /// The crate contains a `Cargo.toml` manifest with the required
+    /// dependencies, a `src/lib.rs` file defining `MAXIMUM_ELEMENTS`
+    /// as the number of hazards rounded up to the next power of two,
+    /// and the `src/ontology.rs` module with the hazards APIs.
+    ///
+    /// The name must be a valid Cargo package name: it must not be empty,
+    /// must not start with a digit and may only contain ASCII alphanumerics,
+    /// `-` and `_`. Otherwise, generating the Rust APIs fails.
+    ///
+    /// Only used by the Rust template.
+    pub fn with_rust_crate(mut self, crate_name: impl Into<String>) -> Self {
+        self.rust_crate = Some(crate_name.into());
+        self
+    }
+
+    /// Sets the profile of the generated Rust APIs, which defines
+    /// the collection used to store sets of hazard data.
+    ///
+    /// The default profile is [`Profile::NoStd`].
+    /// Only used by the Rust template.
+    pub const fn with_rust_profile(mut self, rust_profile: Profile) -> Self {
+        self.rust_profile = rust_profile;
+        self
+    }
+
+    /// Sets how the generated Rust hazards and categories are
+    /// serialized and deserialized when the `serde` feature is enabled.
+    ///
+    /// Hazards are deserialized from their label, their human-readable name
+    /// or their IRI, or only from their identifier with
+    /// [`SerdeRepresentation::Id`].
+    /// The default representation is [`SerdeRepresentation::Label`].
+    /// Only used by the Rust template.
+    pub const fn with_serde_representation(
+        mut self,
+        serde_representation: SerdeRepresentation,
+    ) -> Self {
+        self.serde_representation = serde_representation;
+        self
+    }
+
+    /// Sets a directory containing user-supplied templates.
+    ///
+    /// A template in this directory overrides the builtin template with
+    /// the same name, such as `rs.ontology` or `rs.risk`.
+    /// Builtin templates are always available with the `builtin/` prefix,
+    /// so that user-supplied templates can extend them, for example
+    /// `{% extends "builtin/rs.ontology" %}`.
+    pub fn with_template_dir(mut self, template_dir: impl Into<PathBuf>) -> Self {
+        self.template_dir = Some(template_dir.into());
+        self
+    }
+
+    /// Sets the path of the hazard identifiers lock file.
+    ///
+    /// The lock file maps each hazard IRI to its identifier, so that
+    /// identifiers do not change when hazards are added to the ontology.
+    /// The lock file is created if it does not exist, and it is updated
+    /// with the identifiers of the new hazards each time the APIs are
+    /// rendered. [`Self::generate`] only reads it.
+    /// The identifiers of removed hazards are retired and never reused.
+    ///
+    /// Identifiers explicitly defined in the ontology through
+    /// the `sho:identifier` property always take precedence, and they
+    /// must not conflict with the lock file.
+    pub fn with_id_lock(mut self, id_lock: impl Into<PathBuf>) -> Self {
+        self.id_lock = Some(id_lock.into());
+        self
+    }
+
+    /// Sets the format of the ontology file.
+    ///
+    /// When not set, the format is detected from the extension of the
+    /// ontology file: `.ttl` for Turtle, `.nt` for N-Triples, `.nq` for
+    /// N-Quads, `.owl` and `.rdf` for RDF/XML, and JSON-LD otherwise.
+    pub const fn with_input_format(mut self, input_format: InputFormat) -> Self {
+        self.input_format = Some(input_format);
+        self
+    }
+
+    /// Maps a remote JSON-LD context IRI to a local file.
+    ///
+    /// Ontologies referencing the context IRI in their `@context` are
+    /// expanded using the local file, without accessing the network.
+    pub fn with_context(mut self, iri: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        self.contexts.push((iri.into(), path.into()));
+        self
+    }
+
+    /// Sets a cache directory containing remote JSON-LD contexts.
+    ///
+    /// A context is looked up in the cache directory when it is not
+    /// mapped with [`Self::with_context`]. It is stored at the path formed
+    /// by the authority and the path of its IRI, for example the context
+    /// `https://www.w3.org/2019/wot/td/v1` is stored at
+    /// `<cache>/www.w3.org/2019/wot/td/v1`.
+    pub fn with_context_cache(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.context_cache = Some(cache_dir.into());
+        self
+    }
+
+    /// Loads the hazard ontology.
+    ///
+    /// When several ontology files are given, they are merged into
+    /// a single ontology, so that their hazards and categories are
+    /// generated together. The same hazard or category can be defined
+    /// in more than one file only if all its definitions are equal.
+    pub fn load<P: AsRef<Path>>(
+        &self,
+        ontology_paths: impl IntoIterator<Item = P>,
+    ) -> Result<HazardOntology> {
+        let ontology = self.open_ontologies(ontology_paths)?;
+
+        HazardOntology::extract(&ontology)
+    }
+
     /// Runs hazards producer.
+    ///
+    /// Loads the ontology files as in [`Self::load`], and then
+    /// renders the APIs as in [`Self::render`].
     pub fn run<P: AsRef<Path>, O: AsRef<Path>>(
         self,
-        ontology_path: P,
+        ontology_paths: impl IntoIterator<Item = P>,
+        output_path: O,
+        template_type: TemplateType,
+    ) -> Result<()> {
+        // Obtain the ontology.
+        let ontology = self.load(ontology_paths)?;
+
+        self.render(&ontology, output_path, template_type)
+    }
+
+    /// Renders the APIs of a hazard ontology into the output directory.
+    ///
+    /// The files are generated as in [`Self::generate`], and then
+    /// written into the output directory. The identifiers lock file,
+    /// if any, is updated as well.
+    pub fn render<O: AsRef<Path>>(
+        &self,
+        ontology: &HazardOntology,
         output_path: O,
         template_type: TemplateType,
     ) -> Result<()> {
         // Check output path.
         self.check_output_path(&output_path)?;
 
-        // Obtain the ontology.
-        let ontology = self.open_ontology(ontology_path)?;
+        let (files, id_lock) = self.generate_files(ontology, template_type)?;
 
-        // Build the tamplate.
+        for file in files {
+            let path = output_path.as_ref().join(file.path());
+            if let Some(parent) = path.parent() {
+                create_dir_all(parent)?;
+            }
+            write(path, file.contents())?;
+        }
+
+        // Update the identifiers lock file.
+        if let Some(id_lock_path) = &self.id_lock {
+            id_lock.save(id_lock_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Generates the APIs of a hazard ontology in memory.
+    ///
+    /// Returns the generated files, whose paths are relative to
+    /// the output directory, without writing anything to disk.
+    /// The identifiers lock file, if any, is read but never updated.
+    pub fn generate(
+        &self,
+        ontology: &HazardOntology,
+        template_type: TemplateType,
+    ) -> Result<Vec<GeneratedFile>> {
+        self.generate_files(ontology, template_type)
+            .map(|(files, _)| files)
+    }
+
+    // Generates the files of the APIs, together with the updated
+    // identifiers lock file.
+    fn generate_files(
+        &self,
+        ontology: &HazardOntology,
+        template_type: TemplateType,
+    ) -> Result<(Vec<GeneratedFile>, IdLock)> {
+        // Check template directory.
+        self.check_template_dir()?;
+
+        // Check the name of the generated Rust crate.
+        if matches!(template_type, TemplateType::Rust) {
+            self.check_rust_crate()?;
+        }
+
+        // Assign an identifier to each hazard.
+        let (identifiers, id_lock) = self.assign_identifiers(ontology)?;
+
+        // Build the template.
         let template = match template_type {
-            TemplateType::Rust => Rust::new().build(ontology, output_path.as_ref(), self.with_risk),
+            TemplateType::Rust => self.build(
+                Rust::new(
+                    self.rust_crate.as_deref(),
+                    self.rust_profile,
+                    self.serde_representation,
+                ),
+                ontology,
+                &identifiers,
+            ),
+            TemplateType::C => self.build(C::new(), ontology, &identifiers),
+            TemplateType::Cpp => self.build(Cpp::new(), ontology, &identifiers),
+            TemplateType::Python => self.build(Python::new(), ontology, &identifiers),
+            TemplateType::TypeScript => self.build(TypeScript::new(), ontology, &identifiers),
+            TemplateType::Go => self.build(Go::new(), ontology, &identifiers),
+            TemplateType::Java => {
+                self.build(Java::new(self.package.as_deref()), ontology, &identifiers)
+            }
+            TemplateType::Markdown => self.build(Markdown::new(), ontology, &identifiers),
+            TemplateType::JsonSchema => self.build(JsonSchema::new(), ontology, &identifiers),
+            TemplateType::Protobuf => self.build(Protobuf::new(), ontology, &identifiers),
         };
 
         // Render the template.
-        template.render()
+        let files = template.render()?;
+
+        Ok((files, id_lock))
+    }
+
+    /// Validates the structure of the ontology.
+    ///
+    /// Reports missing labels, missing categories, categories that are
+    /// referenced but never declared, duplicate labels, labels that are
+    /// not valid identifiers, invalid explicit hazard identifiers, risk
+    /// score ranges declared more than once, and risk scores outside the
+    /// declared range.
+    /// An empty list means that the ontology can be used to generate APIs.
+    ///
+    /// Several ontology files are merged as in [`Self::run`].
+    pub fn validate<P: AsRef<Path>>(
+        &self,
+        ontology_paths: impl IntoIterator<Item = P>,
+    ) -> Result<Vec<ValidationIssue>> {
+        let ontology = self.open_ontologies(ontology_paths)?;
+
+        Ok(validation::validate(&ontology))
+    }
+
+    fn open_ontologies<P: AsRef<Path>>(
+        &self,
+        ontology_paths: impl IntoIterator<Item = P>,
+    ) -> Result<Ontology> {
+        let mut ontologies = ontology_paths
+            .into_iter()
+            .map(|path| {
+                let ontology = self.open_ontology(&path)?;
+                Ok((path.as_ref().to_path_buf(), ontology))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Merge the ontologies only when there is more than one.
+        match ontologies.len() {
+            0 => Err(Error::new(
+                ErrorKind::PathFormat,
+                "At least one ontology path MUST be provided",
+            )),
+            1 => Ok(ontologies.remove(0).1),
+            _ => merge(ontologies),
+        }
     }
 
     fn open_ontology<P: AsRef<Path>>(&self, ontology_path: P) -> error::Result<Ontology> {
@@ -251,17 +862,29 @@ impl HazardsProducer {
             ));
         }
 
-        // Create a `RemoteDocument` by parsing the local ontology file.
-        let input: json_ld::RemoteDocument = RemoteDocument::new(
-            // Use `None` since the ontology file is a local file that does not have an URL.
-            None,
-            None,
-            // Parse the file.
-            json_ld::syntax::Value::parse_str(read_to_string(ontology_path)?.as_str())?.0,
-        );
+        // Detect the ontology format.
+        let input_format = self
+            .input_format
+            .or_else(|| InputFormat::from_path(ontology_path.as_ref()))
+            .unwrap_or(InputFormat::JsonLd);
+
+        // Parse the local ontology file. RDF formats are converted into
+        // an expanded JSON-LD document.
+        let input = read_to_string(ontology_path)?;
+        let document = match input_format {
+            InputFormat::JsonLd => json_ld::syntax::Value::parse_str(input.as_str())?.0,
+            InputFormat::Turtle => rdf::turtle(&input)?,
+            InputFormat::NTriples => rdf::n_triples(&input)?,
+            InputFormat::NQuads => rdf::n_quads(&input)?,
+            InputFormat::RdfXml => rdf::rdf_xml(&input)?,
+        };
 
-        // Use `NoLoader` as we won't need to load any remote document.
-        let mut loader = json_ld::NoLoader;
+        // Create a `RemoteDocument` from the parsed ontology.
+        // Use `None` since the ontology file is a local file that does not have an URL.
+        let input: json_ld::RemoteDocument = RemoteDocument::new(None, None, document);
+
+        // Load remote documents from local files only.
+        let mut loader = self.loader()?;
 
         // Expand the jsonld ontology.
         let expanded = executor::block_on(input.expand(&mut loader))?;
@@ -275,6 +898,91 @@ impl HazardsProducer {
         Ok(ontology)
     }
 
+    fn loader(&self) -> Result<LocalLoader> {
+        let contexts = self
+            .contexts
+            .iter()
+            .map(|(iri, path)| {
+                IriBuf::new(iri.clone())
+                    .map(|iri| (iri, path.clone()))
+                    .map_err(|_| {
+                        Error::new(
+                            ErrorKind::JsonLd,
+                            format!("{iri:?} is not a valid context IRI"),
+                        )
+                    })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(LocalLoader::new(contexts, self.context_cache.clone()))
+    }
+
+    fn build<T: BuildTemplate>(
+        &self,
+        template: T,
+        ontology: &HazardOntology,
+        identifiers: &Identifiers,
+    ) -> Template {
+        template.build(
+            ontology,
+            self.with_risk,
+            self.template_dir.as_deref(),
+            identifiers,
+        )
+    }
+
+    fn assign_identifiers(&self, ontology: &HazardOntology) -> Result<(Identifiers, IdLock)> {
+        // Read the identifiers lock file, if any.
+        let id_lock = match &self.id_lock {
+            Some(id_lock_path) => IdLock::open(id_lock_path)?,
+            None => IdLock::default(),
+        };
+
+        // Retrieve the IRI and the explicit identifier of each hazard.
+        let hazards = ontology
+            .hazards
+            .iter()
+            .map(|hazard| (hazard.iri.as_str(), hazard.identifier));
+
+        Identifiers::assign(hazards, &id_lock)
+    }
+
+    #[inline(always)]
+    fn check_template_dir(&self) -> error::Result<()> {
+        // Return an error if the template directory is not a directory.
+        if let Some(template_dir) = &self.template_dir {
+            if !template_dir.is_dir() {
+                return Err(Error::new(
+                    ErrorKind::PathFormat,
+                    "Template path MUST be a directory path",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_rust_crate(&self) -> error::Result<()> {
+        // Return an error if the crate name is not a valid Cargo package name.
+        if let Some(crate_name) = &self.rust_crate {
+            let is_valid = crate_name
+                .chars()
+                .next()
+                .is_some_and(|c| !c.is_ascii_digit())
+                && crate_name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if !is_valid {
+                return Err(Error::new(
+                    ErrorKind::Template,
+                    format!("{crate_name:?} is not a valid Rust crate name."),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     #[inline(always)]
     fn check_output_path<P: AsRef<Path>>(&self, output_path: P) -> error::Result<()> {
         // Return an error if output path is not a directory.
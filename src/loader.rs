@@ -0,0 +1,111 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::read_to_string,
+    path::{Component, Path, PathBuf},
+};
+
+use iref::{Iri, IriBuf};
+use json_ld::{syntax::Parse, Loader, LoadingResult, RemoteDocument};
+use rdf_types::vocabulary::IriVocabulary;
+
+// Error returned when a remote document cannot be loaded from a local file.
+#[derive(Debug)]
+pub(crate) struct LoadError {
+    iri: IriBuf,
+    reason: String,
+}
+
+impl LoadError {
+    fn new(iri: IriBuf, reason: impl Into<String>) -> Self {
+        Self {
+            iri,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot load {}: {}", self.iri, self.reason)
+    }
+}
+
+// Loads remote JSON-LD documents, such as `@context` references,
+// from local files, so that ontologies can be expanded offline.
+//
+// A document is searched in the explicit IRI to path mappings first,
+// then in the cache directory, where it is stored at the path formed
+// by the authority and the path segments of its IRI.
+// For example, `https://www.w3.org/2019/wot/td/v1` is stored at
+// `<cache>/www.w3.org/2019/wot/td/v1`.
+pub(crate) struct LocalLoader {
+    contexts: HashMap<IriBuf, PathBuf>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl LocalLoader {
+    pub(crate) fn new(contexts: HashMap<IriBuf, PathBuf>, cache_dir: Option<PathBuf>) -> Self {
+        Self {
+            contexts,
+            cache_dir,
+        }
+    }
+
+    // Retrieves the local path of a remote document.
+    fn path(&self, iri: &Iri) -> Option<PathBuf> {
+        if let Some(path) = self.contexts.get(iri) {
+            return Some(path.clone());
+        }
+
+        // Do not allow an authority to escape the cache directory either.
+        let authority = Path::new(iri.authority()?.as_str());
+        let mut components = authority.components();
+        if !matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        ) {
+            return None;
+        }
+
+        let mut path = self.cache_dir.as_deref()?.join(authority);
+        for segment in iri.path().segments() {
+            // Do not allow to escape the cache directory.
+            if matches!(segment.as_str(), "" | "." | "..") {
+                return None;
+            }
+            path.push(segment.as_str());
+        }
+
+        Some(path)
+    }
+
+    fn load_document(path: &Path, iri: &Iri) -> Result<json_ld::syntax::Value, LoadError> {
+        let contents = read_to_string(path)
+            .map_err(|e| LoadError::new(iri.to_owned(), format!("{}: {e}", path.display())))?;
+
+        json_ld::syntax::Value::parse_str(&contents)
+            .map(|(document, _)| document)
+            .map_err(|e| LoadError::new(iri.to_owned(), format!("{}: {e}", path.display())))
+    }
+}
+
+impl Loader<IriBuf> for LocalLoader {
+    type Error = LoadError;
+
+    async fn load_with<V>(
+        &mut self,
+        _vocabulary: &mut V,
+        url: IriBuf,
+    ) -> LoadingResult<IriBuf, LoadError>
+    where
+        V: IriVocabulary<Iri = IriBuf>,
+    {
+        let path = self
+            .path(&url)
+            .ok_or_else(|| LoadError::new(url.clone(), "no local document is available"))?;
+        let document = Self::load_document(&path, &url)?;
+
+        Ok(RemoteDocument::new(Some(url), None, document))
+    }
+}
@@ -0,0 +1,87 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use crate::{
+    error::{Error, ErrorKind, Result},
+    toolchain::{category, description, iri, is_hazard, label, object_type, risk_score},
+    Ontology,
+};
+
+// Definition of a hazard or a category, used to detect conflicts
+// between ontologies.
+struct Definition {
+    path: PathBuf,
+    category: String,
+    description: String,
+    risk_score: Option<u8>,
+}
+
+// Merges several ontologies into a single one.
+//
+// A hazard or a category with the same label of one defined in a previous
+// ontology is dropped when the two definitions are equal, otherwise an
+// error is returned.
+// The merged objects are sorted by their IRI, as in a single ontology.
+pub(crate) fn merge(ontologies: Vec<(PathBuf, Ontology)>) -> Result<Ontology> {
+    let mut definitions: HashMap<(bool, String), Definition> = HashMap::new();
+    let mut merged = Ontology::new();
+
+    for (path, ontology) in ontologies {
+        let mut new_definitions = HashMap::new();
+
+        for object in ontology {
+            let Some(object_type) = object_type(&object) else {
+                merged.push(object);
+                continue;
+            };
+
+            let key = (is_hazard(object_type), label(&object).to_owned());
+            let definition = Definition {
+                path: path.clone(),
+                category: category(&object).to_owned(),
+                description: description(&object).to_owned(),
+                risk_score: risk_score(&object),
+            };
+
+            match definitions.get(&key) {
+                Some(previous) => check_conflict(&key, previous, &definition)?,
+                None => {
+                    new_definitions.insert(key, definition);
+                    merged.push(object);
+                }
+            }
+        }
+
+        definitions.extend(new_definitions);
+    }
+
+    merged.sort_by(|a, b| iri(a).cmp(iri(b)));
+
+    Ok(merged)
+}
+
+fn check_conflict(
+    (is_hazard, label): &(bool, String),
+    first: &Definition,
+    second: &Definition,
+) -> Result<()> {
+    let field = if first.category != second.category {
+        "category"
+    } else if first.description != second.description {
+        "description"
+    } else if first.risk_score != second.risk_score {
+        "risk score"
+    } else {
+        return Ok(());
+    };
+
+    let kind = if *is_hazard { "Hazard" } else { "Category" };
+
+    Err(Error::new(
+        ErrorKind::Merge,
+        format!(
+            "{kind} {label:?} is defined in {} and {} with a conflicting {field}",
+            first.path.display(),
+            second.path.display(),
+        ),
+    ))
+}
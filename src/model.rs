@@ -0,0 +1,208 @@
+use serde::Serialize;
+use static_iref::iri;
+
+use crate::{
+    error::{Error, ErrorKind, Result},
+    toolchain::{
+        category_iri, comment, description, identifier, iri, is_category, is_hazard, is_ontology,
+        label, max_inclusive, min_inclusive, name, object_type, risk_score, string_property,
+    },
+    Ontology,
+};
+
+/// Metadata of a hazard ontology.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OntologyMetadata {
+    /// IRI of the ontology.
+    pub iri: Option<String>,
+    /// Title of the ontology.
+    pub title: Option<String>,
+    /// Description of the ontology.
+    pub description: Option<String>,
+    /// Version of the ontology.
+    pub version: Option<String>,
+    /// License of the ontology.
+    pub license: Option<String>,
+}
+
+/// Range of the risk score declared in a hazard ontology.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct RiskScoreRange {
+    /// Inclusive lower bound.
+    pub min: Option<u8>,
+    /// Inclusive upper bound.
+    pub max: Option<u8>,
+}
+
+impl RiskScoreRange {
+    // Bounds used when the ontology does not declare them.
+    const DEFAULT_MIN: u8 = 0;
+    const DEFAULT_MAX: u8 = 10;
+
+    // Extracts the risk score range from the `xsd:minInclusive` and
+    // `xsd:maxInclusive` restrictions of the ontology objects.
+    //
+    // The first declaration of each bound is used, while the `@id`s of
+    // the objects declaring a bound again are returned, so that they can
+    // be reported.
+    pub(crate) fn extract(ontology: &Ontology) -> (Self, Vec<&str>) {
+        let mut range = Self::default();
+        let mut duplicates = Vec::new();
+
+        for object in ontology {
+            let min = min_inclusive(object);
+            let max = max_inclusive(object);
+
+            if (min.is_some() && range.min.is_some()) || (max.is_some() && range.max.is_some()) {
+                duplicates.push(iri(object));
+                continue;
+            }
+
+            range.min = range.min.or(min);
+            range.max = range.max.or(max);
+        }
+
+        (range, duplicates)
+    }
+
+    // Returns the inclusive bounds, using the default ones when
+    // the ontology does not declare them.
+    pub(crate) fn bounds(&self) -> (u8, u8) {
+        (
+            self.min.unwrap_or(Self::DEFAULT_MIN),
+            self.max.unwrap_or(Self::DEFAULT_MAX),
+        )
+    }
+}
+
+/// A hazard of a hazard ontology.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Hazard {
+    /// IRI of the hazard.
+    pub iri: String,
+    /// Label of the hazard, used as identifier in the generated APIs.
+    pub label: String,
+    /// Human-readable name of the hazard.
+    pub name: String,
+    /// Description of the hazard.
+    pub description: String,
+    /// Comment about the hazard.
+    pub comment: String,
+    /// IRI of the hazard category.
+    pub category: String,
+    /// Risk score of the hazard.
+    pub risk_score: Option<u8>,
+    /// Identifier explicitly assigned to the hazard.
+    pub identifier: Option<u16>,
+}
+
+impl Hazard {
+    /// Returns the name of the hazard category, that is the fragment
+    /// of its IRI.
+    pub fn category_name(&self) -> &str {
+        self.category.split('#').next_back().unwrap_or_default()
+    }
+}
+
+/// A category of a hazard ontology.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Category {
+    /// IRI of the category.
+    pub iri: String,
+    /// Label of the category, used as identifier in the generated APIs.
+    pub label: String,
+    /// Human-readable name of the category.
+    pub name: String,
+    /// Description of the category.
+    pub description: String,
+    /// Comment about the category.
+    pub comment: String,
+}
+
+/// A hazard ontology, with the hazards and categories used
+/// to generate the APIs.
+///
+/// It can be inspected or modified before generating the APIs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HazardOntology {
+    /// Metadata of the ontology.
+    pub metadata: OntologyMetadata,
+    /// Hazards, in the order they are generated.
+    pub hazards: Vec<Hazard>,
+    /// Categories, in the order they are generated.
+    pub categories: Vec<Category>,
+    /// Declared range of the risk score.
+    pub risk_score_range: RiskScoreRange,
+}
+
+impl HazardOntology {
+    // Extracts the hazard ontology from the ontology objects.
+    //
+    // Returns an error when a hazard has an invalid explicit identifier,
+    // instead of assigning it a fresh one, or when a bound of the risk
+    // score range is declared more than once.
+    pub(crate) fn extract(ontology: &Ontology) -> Result<Self> {
+        let mut hazard_ontology = Self::default();
+
+        let (risk_score_range, duplicates) = RiskScoreRange::extract(ontology);
+        if let Some(id) = duplicates.first() {
+            return Err(Error::new(
+                ErrorKind::Format,
+                format!("The risk score range is declared again by {id}"),
+            ));
+        }
+        hazard_ontology.risk_score_range = risk_score_range;
+
+        for object in ontology {
+            if is_ontology(object) {
+                hazard_ontology.metadata = OntologyMetadata {
+                    iri: Some(iri(object).to_owned()),
+                    title: string_property(object, iri!("http://purl.org/dc/terms/title")),
+                    description: string_property(
+                        object,
+                        iri!("http://purl.org/dc/terms/description"),
+                    ),
+                    version: string_property(
+                        object,
+                        iri!("http://www.w3.org/2002/07/owl#versionInfo"),
+                    ),
+                    license: string_property(object, iri!("http://purl.org/dc/terms/license")),
+                };
+            }
+
+            match object_type(object) {
+                Some(object_type) if is_hazard(object_type) => {
+                    let identifier = identifier(object).map_err(|value| {
+                        Error::new(
+                            ErrorKind::Identifier,
+                            format!("Hazard {} has an invalid identifier {value}", iri(object)),
+                        )
+                    })?;
+
+                    hazard_ontology.hazards.push(Hazard {
+                        iri: iri(object).to_owned(),
+                        label: label(object).to_owned(),
+                        name: name(object).to_owned(),
+                        description: description(object).to_owned(),
+                        comment: comment(object).to_owned(),
+                        category: category_iri(object).unwrap_or_default().to_owned(),
+                        risk_score: risk_score(object),
+                        identifier,
+                    });
+                }
+                Some(object_type) if is_category(object_type) => {
+                    hazard_ontology.categories.push(Category {
+                        iri: iri(object).to_owned(),
+                        label: label(object).to_owned(),
+                        name: name(object).to_owned(),
+                        description: description(object).to_owned(),
+                        comment: comment(object).to_owned(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(hazard_ontology)
+    }
+}
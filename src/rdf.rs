@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use json_ld::syntax::{Object, Value};
+use oxrdf::{vocab::xsd, Literal, NamedOrBlankNode, Term, Triple};
+use oxrdfxml::RdfXmlParser;
+use oxttl::{NQuadsParser, NTriplesParser, TurtleParser};
+
+use crate::error::Result;
+
+// Parses a Turtle ontology into an expanded JSON-LD document.
+pub(crate) fn turtle(input: &str) -> Result<Value> {
+    let triples = TurtleParser::new()
+        .for_slice(input)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(document(triples))
+}
+
+// Parses an N-Triples ontology into an expanded JSON-LD document.
+pub(crate) fn n_triples(input: &str) -> Result<Value> {
+    let triples = NTriplesParser::new()
+        .for_slice(input)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(document(triples))
+}
+
+// Parses an N-Quads ontology into an expanded JSON-LD document.
+//
+// Graph names are ignored, so the quads of all graphs are merged
+// into a single graph.
+pub(crate) fn n_quads(input: &str) -> Result<Value> {
+    let quads = NQuadsParser::new()
+        .for_slice(input)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(document(quads.into_iter().map(Triple::from)))
+}
+
+// Parses an RDF/XML ontology into an expanded JSON-LD document.
+pub(crate) fn rdf_xml(input: &str) -> Result<Value> {
+    let triples = RdfXmlParser::new()
+        .for_slice(input)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(document(triples))
+}
+
+// Builds an expanded JSON-LD document from RDF triples.
+//
+// The document follows the same expansion and flattening steps
+// of a JSON-LD ontology, so hazards and categories are extracted
+// in the same way regardless of the input format.
+fn document(triples: impl IntoIterator<Item = Triple>) -> Value {
+    // Group the triples by subject and predicate.
+    let mut nodes: BTreeMap<String, BTreeMap<String, Vec<Value>>> = BTreeMap::new();
+    for triple in triples {
+        nodes
+            .entry(node_id(triple.subject))
+            .or_default()
+            .entry(triple.predicate.into_string())
+            .or_default()
+            .push(object(triple.object));
+    }
+
+    nodes
+        .into_iter()
+        .map(|(id, properties)| {
+            let mut node = Object::new();
+            node.push("@id".into(), Value::String(id.into()));
+            for (predicate, objects) in properties {
+                node.push(predicate.as_str().into(), Value::Array(objects));
+            }
+            Value::Object(node)
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+// Retrieves the JSON-LD identifier of a subject.
+fn node_id(subject: NamedOrBlankNode) -> String {
+    match subject {
+        NamedOrBlankNode::NamedNode(node) => node.into_string(),
+        NamedOrBlankNode::BlankNode(node) => format!("_:{}", node.as_str()),
+    }
+}
+
+// Converts an RDF object into a JSON-LD node reference or value object.
+fn object(term: Term) -> Value {
+    let mut object = Object::new();
+    match term {
+        Term::NamedNode(node) => {
+            object.push("@id".into(), Value::String(node.as_str().into()));
+        }
+        Term::BlankNode(node) => {
+            object.push(
+                "@id".into(),
+                Value::String(format!("_:{}", node.as_str()).into()),
+            );
+        }
+        Term::Literal(literal) => value(&mut object, literal),
+    }
+    Value::Object(object)
+}
+
+// Fills in a JSON-LD value object from an RDF literal.
+//
+// Integers are converted into native JSON numbers, as they are
+// written in JSON-LD ontologies.
+fn value(object: &mut Object, literal: Literal) {
+    let lexical = Value::String(literal.value().into());
+
+    if let Some(language) = literal.language() {
+        object.push("@value".into(), lexical);
+        object.push("@language".into(), Value::String(language.into()));
+        return;
+    }
+
+    let datatype = literal.datatype();
+    if datatype == xsd::STRING {
+        object.push("@value".into(), lexical);
+    } else if let Some(number) = (datatype == xsd::INTEGER)
+        .then(|| literal.value().parse::<i64>().ok())
+        .flatten()
+    {
+        object.push("@value".into(), number.into());
+    } else {
+        object.push("@type".into(), Value::String(datatype.as_str().into()));
+        object.push("@value".into(), lexical);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{BuildTemplate, OutputFile};
+
+use super::builtin_templates;
+
+const OUTPUT_HEADER_FILE: &str = "hazards.h";
+const OUTPUT_SOURCE_FILE: &str = "hazards.c";
+
+static C_TEMPLATES: &[(&str, &str)] = &builtin_templates!["c" =>
+    ("c.header", "hazards.h"),
+    ("c.source", "hazards.c")
+];
+
+static C_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["c" =>
+    ("c.header.risk", "risk.h"),
+    ("c.source.risk", "risk.c")
+];
+
+pub(crate) struct C;
+
+impl C {
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+}
+
+impl BuildTemplate for C {
+    #[inline(always)]
+    fn get_templates() -> &'static [(&'static str, &'static str)] {
+        C_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        C_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        // The source file includes the header file, so their names
+        // do not change when the risk score is parsed.
+        match with_risk {
+            true => vec![
+                OutputFile::new(OUTPUT_HEADER_FILE, "c.header.risk"),
+                OutputFile::new(OUTPUT_SOURCE_FILE, "c.source.risk"),
+            ],
+            false => vec![
+                OutputFile::new(OUTPUT_HEADER_FILE, "c.header"),
+                OutputFile::new(OUTPUT_SOURCE_FILE, "c.source"),
+            ],
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{BuildTemplate, OutputFile};
+
+use super::builtin_templates;
+
+const OUTPUT_FILE: &str = "hazards.hpp";
+
+static CPP_TEMPLATES: &[(&str, &str)] = &builtin_templates!["cpp" =>
+    ("cpp.hazards", "hazards.hpp")
+];
+
+static CPP_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["cpp" =>
+    ("cpp.risk", "risk.hpp")
+];
+
+pub(crate) struct Cpp;
+
+impl Cpp {
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+}
+
+impl BuildTemplate for Cpp {
+    #[inline(always)]
+    fn get_templates() -> &'static [(&'static str, &'static str)] {
+        CPP_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        CPP_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        let template_name = match with_risk {
+            true => "cpp.risk",
+            false => "cpp.hazards",
+        };
+
+        vec![OutputFile::new(OUTPUT_FILE, template_name)]
+    }
+}
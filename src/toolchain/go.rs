@@ -0,0 +1,43 @@
+use crate::{BuildTemplate, OutputFile};
+
+use super::builtin_templates;
+
+const OUTPUT_FILE: &str = "hazards.go";
+
+static GO_TEMPLATES: &[(&str, &str)] = &builtin_templates!["go" =>
+    ("go.hazards", "hazards.go")
+];
+
+static GO_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["go" =>
+    ("go.risk", "risk.go")
+];
+
+pub(crate) struct Go;
+
+impl Go {
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+}
+
+impl BuildTemplate for Go {
+    #[inline(always)]
+    fn get_templates() -> &'static [(&'static str, &'static str)] {
+        GO_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        GO_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        let template_name = match with_risk {
+            true => "go.risk",
+            false => "go.hazards",
+        };
+
+        vec![OutputFile::new(OUTPUT_FILE, template_name)]
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use minijinja::Value;
+
+use crate::{BuildTemplate, OutputFile};
+
+use super::builtin_templates;
+
+const OUTPUT_HAZARD_FILE: &str = "Hazard.java";
+const OUTPUT_CATEGORY_FILE: &str = "Category.java";
+
+static JAVA_TEMPLATES: &[(&str, &str)] = &builtin_templates!["java" =>
+    ("java.hazard", "Hazard.java"),
+    ("java.category", "Category.java")
+];
+
+static JAVA_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["java" =>
+    ("java.risk", "risk.java")
+];
+
+pub(crate) struct Java<'a> {
+    package: Option<&'a str>,
+}
+
+impl<'a> Java<'a> {
+    pub(crate) const fn new(package: Option<&'a str>) -> Self {
+        Self { package }
+    }
+}
+
+impl<'a> BuildTemplate for Java<'a> {
+    #[inline(always)]
+    fn get_templates() -> &'static [(&'static str, &'static str)] {
+        JAVA_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        JAVA_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        let hazard_template_name = match with_risk {
+            true => "java.risk",
+            false => "java.hazard",
+        };
+
+        vec![
+            OutputFile::new(OUTPUT_HAZARD_FILE, hazard_template_name),
+            OutputFile::new(OUTPUT_CATEGORY_FILE, "java.category"),
+        ]
+    }
+
+    fn extend_context(&self, context: &mut HashMap<&'static str, Value>) {
+        // Add the package of the generated enums.
+        context.insert("package", Value::from(self.package));
+    }
+}
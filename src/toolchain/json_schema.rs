@@ -0,0 +1,43 @@
+use crate::{BuildTemplate, OutputFile};
+
+use super::builtin_templates;
+
+const OUTPUT_FILE: &str = "hazards.schema.json";
+
+static JSON_SCHEMA_TEMPLATES: &[(&str, &str)] = &builtin_templates!["json-schema" =>
+    ("json.schema", "hazards.schema.json")
+];
+
+static JSON_SCHEMA_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["json-schema" =>
+    ("json.risk", "risk.schema.json")
+];
+
+pub(crate) struct JsonSchema;
+
+impl JsonSchema {
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+}
+
+impl BuildTemplate for JsonSchema {
+    #[inline(always)]
+    fn get_templates() -> &'static [(&'static str, &'static str)] {
+        JSON_SCHEMA_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        JSON_SCHEMA_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        let template_name = match with_risk {
+            true => "json.risk",
+            false => "json.schema",
+        };
+
+        vec![OutputFile::new(OUTPUT_FILE, template_name)]
+    }
+}
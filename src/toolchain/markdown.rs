@@ -0,0 +1,43 @@
+use crate::{BuildTemplate, OutputFile};
+
+use super::builtin_templates;
+
+const OUTPUT_FILE: &str = "hazards.md";
+
+static MARKDOWN_TEMPLATES: &[(&str, &str)] = &builtin_templates!["markdown" =>
+    ("md.hazards", "hazards.md")
+];
+
+static MARKDOWN_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["markdown" =>
+    ("md.risk", "risk.md")
+];
+
+pub(crate) struct Markdown;
+
+impl Markdown {
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+}
+
+impl BuildTemplate for Markdown {
+    #[inline(always)]
+    fn get_templates() -> &'static [(&'static str, &'static str)] {
+        MARKDOWN_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        MARKDOWN_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        let template_name = match with_risk {
+            true => "md.risk",
+            false => "md.hazards",
+        };
+
+        vec![OutputFile::new(OUTPUT_FILE, template_name)]
+    }
+}
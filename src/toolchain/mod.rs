@@ -1,11 +1,23 @@
+pub mod c;
+pub mod cpp;
+pub mod go;
+pub mod java;
+pub mod json_schema;
+pub mod markdown;
+pub mod protobuf;
+pub mod python;
 pub mod rust;
+pub mod typescript;
 
-use json_ld::{object::Any, Indexed, IndexedNode, Node};
+use iref::Iri;
+use json_ld::{object::Any, Indexed, Node};
 use minijinja::Value;
 use serde::Serialize;
 use static_iref::iri;
 use std::collections::HashMap;
 
+use crate::model::RiskScoreRange;
+
 macro_rules! builtin_templates {
     ($root:expr => $(($name:expr, $template:expr)),+) => {
         [
@@ -22,6 +34,8 @@ pub(crate) use builtin_templates;
 
 #[derive(Serialize)]
 pub(crate) struct HazardData<'a> {
+    pub(crate) id: u16,
+    iri: &'a str,
     name: &'a str,
     description: &'a str,
     pub(crate) category: &'a str,
@@ -30,12 +44,16 @@ pub(crate) struct HazardData<'a> {
 
 impl<'a> HazardData<'a> {
     pub(crate) const fn new(
+        id: u16,
+        iri: &'a str,
         name: &'a str,
         description: &'a str,
         category: &'a str,
         risk_score: Option<u8>,
     ) -> Self {
         Self {
+            id,
+            iri,
             name,
             description,
             category,
@@ -46,14 +64,21 @@ impl<'a> HazardData<'a> {
 
 #[derive(Serialize)]
 pub(crate) struct CategoryData<'a> {
+    iri: &'a str,
     pub(crate) name: &'a str,
     description: &'a str,
     pub(crate) hazards: Vec<&'a str>,
 }
 
 impl<'a> CategoryData<'a> {
-    pub(crate) const fn new(name: &'a str, description: &'a str, hazards: Vec<&'a str>) -> Self {
+    pub(crate) const fn new(
+        iri: &'a str,
+        name: &'a str,
+        description: &'a str,
+        hazards: Vec<&'a str>,
+    ) -> Self {
         Self {
+            iri,
             name,
             description,
             hazards,
@@ -61,19 +86,56 @@ impl<'a> CategoryData<'a> {
     }
 }
 
-// Retrieves object type.
+// Retrieves object type, that is either hazard or category.
+//
+// An object may have several types, such as `owl:NamedIndividual`,
+// both as `rdf:type` properties and as `@type` entries.
 #[inline(always)]
 pub(crate) fn object_type(object: &Indexed<Node>) -> Option<&str> {
     object
-        .get_any(&iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#type"))
-        .and_then(|t| t.as_node())
-        .and_then(|t| t.id())
+        .get(&iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#type"))
+        .filter_map(|t| t.as_node()?.id())
+        .chain(object.types())
         .map(|t| t.as_str())
+        .find(|t| is_hazard(t) || is_category(t))
 }
 
-// Extracts the name from an onotology object.
+// Extracts the IRI of an ontology object.
 #[inline(always)]
-pub(crate) fn name(object_value: &Indexed<Node>) -> &str {
+pub(crate) fn iri(object_value: &Indexed<Node>) -> &str {
+    object_value.id().map(|id| id.as_str()).unwrap_or_default()
+}
+
+// Extracts the explicit identifier of an ontology hazard object.
+//
+// An identifier which is not an integer in the `u16` range is returned
+// as an error, containing the identifier as written in the ontology.
+pub(crate) fn identifier(object_value: &Indexed<Node>) -> Result<Option<u16>, String> {
+    let Some(identifier) = object_value.get_any(&iri!("https://purl.org/sifis/hazards#identifier"))
+    else {
+        return Ok(None);
+    };
+    let literal = identifier
+        .as_value()
+        .and_then(|value| value.as_literal())
+        .map(|(literal, _)| literal);
+
+    literal
+        .and_then(|literal| literal.as_number())
+        .and_then(|number| number.as_u32())
+        .and_then(|id| id.try_into().ok())
+        .map(Some)
+        .ok_or_else(|| {
+            literal.map_or_else(
+                || "a non-literal value".to_owned(),
+                |literal| literal.clone().into_json().to_string(),
+            )
+        })
+}
+
+// Extracts the label from an onotology object.
+#[inline(always)]
+pub(crate) fn label(object_value: &Indexed<Node>) -> &str {
     object_value
         .get_any(&iri!("http://www.w3.org/2000/01/rdf-schema#label"))
         .and_then(|n| n.as_value())
@@ -81,6 +143,33 @@ pub(crate) fn name(object_value: &Indexed<Node>) -> &str {
         .unwrap_or_default()
 }
 
+// Extracts the human-readable name from an onotology object.
+#[inline(always)]
+pub(crate) fn name(object_value: &Indexed<Node>) -> &str {
+    object_value
+        .get_any(&iri!("https://purl.org/sifis/hazards#name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or_default()
+}
+
+// Extracts the comment from an onotology object.
+#[inline(always)]
+pub(crate) fn comment(object_value: &Indexed<Node>) -> &str {
+    object_value
+        .get_any(&iri!("http://www.w3.org/2000/01/rdf-schema#comment"))
+        .and_then(|c| c.as_str())
+        .unwrap_or_default()
+}
+
+// Extracts a string property from an ontology object.
+#[inline(always)]
+pub(crate) fn string_property(object_value: &Indexed<Node>, property: &Iri) -> Option<String> {
+    object_value
+        .get_any(&property)
+        .and_then(|p| p.as_str())
+        .map(str::to_owned)
+}
+
 // Extracts the description from an onotology object.
 #[inline(always)]
 pub(crate) fn description(object_value: &Indexed<Node>) -> &str {
@@ -90,25 +179,60 @@ pub(crate) fn description(object_value: &Indexed<Node>) -> &str {
         .unwrap_or_default()
 }
 
-// Extracts the category of an onotology hazard object.
+// Extracts the category IRI of an onotology hazard object.
 #[inline(always)]
-pub(crate) fn category(object_value: &Indexed<Node>) -> &str {
+pub(crate) fn category_iri(object_value: &Indexed<Node>) -> Option<&str> {
     object_value
         .get_any(&iri!("https://purl.org/sifis/hazards#hasCategory"))
         .and_then(|c| c.as_str())
+}
+
+// Extracts the category of an onotology hazard object.
+#[inline(always)]
+pub(crate) fn category(object_value: &Indexed<Node>) -> &str {
+    category_iri(object_value)
         .unwrap_or_default()
         .split('#')
-        .last()
+        .next_back()
         .unwrap_or_default()
 }
 
 // Extracts the risk score of an onotology hazard object.
 #[inline(always)]
 pub(crate) fn risk_score(object_value: &Indexed<Node>) -> Option<u8> {
+    risk_score_value(object_value)?.try_into().ok()
+}
+
+// Extracts the risk score of an onotology hazard object,
+// without checking whether it fits the risk score type.
+#[inline(always)]
+pub(crate) fn risk_score_value(object_value: &Indexed<Node>) -> Option<i64> {
     object_value
         .get_any(&iri!("https://purl.org/sifis/hazards#riskScore"))?
         .as_value()?
         .as_number()?
+        .as_i64()
+}
+
+// Extracts the inclusive lower bound of an ontology restriction object.
+#[inline(always)]
+pub(crate) fn min_inclusive(object_value: &Indexed<Node>) -> Option<u8> {
+    object_value
+        .get_any(&iri!("http://www.w3.org/2001/XMLSchema#minInclusive"))?
+        .as_value()?
+        .as_number()?
+        .as_u32()?
+        .try_into()
+        .ok()
+}
+
+// Extracts the inclusive upper bound of an ontology restriction object.
+#[inline(always)]
+pub(crate) fn max_inclusive(object_value: &Indexed<Node>) -> Option<u8> {
+    object_value
+        .get_any(&iri!("http://www.w3.org/2001/XMLSchema#maxInclusive"))?
+        .as_value()?
+        .as_number()?
         .as_u32()?
         .try_into()
         .ok()
@@ -122,6 +246,18 @@ pub(crate) fn is_hazard(object_type: &str) -> bool {
     object_type == HAZARD_TYPE
 }
 
+const ONTOLOGY_TYPE: &str = "http://www.w3.org/2002/07/owl#Ontology";
+
+// Checks whether an ontology object describes the ontology itself.
+#[inline(always)]
+pub(crate) fn is_ontology(object: &Indexed<Node>) -> bool {
+    object
+        .get(&iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#type"))
+        .filter_map(|t| t.as_node()?.id())
+        .chain(object.types())
+        .any(|t| t.as_str() == ONTOLOGY_TYPE)
+}
+
 const CATEGORY_TYPE: &str = "https://purl.org/sifis/hazards#Category";
 
 // Checks whether an ontology object is a category.
@@ -130,35 +266,29 @@ pub(crate) fn is_category(object_type: &str) -> bool {
     object_type == CATEGORY_TYPE
 }
 
-// Creates a new hazard and updates related data structures.
-pub(crate) fn create_hazard<'a>(
-    object_value: &'a IndexedNode,
-    name: &'a str,
-    description: &'a str,
-    with_risk: bool,
-) -> HazardData<'a> {
-    // Retrieve hazard category.
-    let category = category(object_value);
-
-    // Retrieve hazard risk score.
-    let risk_score = match with_risk {
-        true => risk_score(object_value),
-        false => None,
-    };
-
-    HazardData::new(name, description, category, risk_score)
-}
-
 // Creates the template context.
 pub(crate) fn context(
     hazards: Vec<HazardData>,
     categories: Vec<CategoryData>,
+    risk_score_range: RiskScoreRange,
+    retired_ids: &[u16],
 ) -> HashMap<&'static str, Value> {
     let mut context = HashMap::new();
 
     // Create context.
     context.insert("hazards", Value::from_serialize(hazards));
     context.insert("categories", Value::from_serialize(categories));
+    // The bounds of the risk score range are always defined, falling back
+    // to the default ones used by the validation.
+    let (min, max) = risk_score_range.bounds();
+    context.insert(
+        "risk_score_range",
+        Value::from_serialize(RiskScoreRange {
+            min: Some(min),
+            max: Some(max),
+        }),
+    );
+    context.insert("retired_ids", Value::from_serialize(retired_ids));
 
     context
 }
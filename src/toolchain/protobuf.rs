@@ -0,0 +1,43 @@
+use crate::{BuildTemplate, OutputFile};
+
+use super::builtin_templates;
+
+const OUTPUT_FILE: &str = "hazards.proto";
+
+static PROTOBUF_TEMPLATES: &[(&str, &str)] = &builtin_templates!["protobuf" =>
+    ("proto.hazards", "hazards.proto")
+];
+
+static PROTOBUF_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["protobuf" =>
+    ("proto.risk", "risk.proto")
+];
+
+pub(crate) struct Protobuf;
+
+impl Protobuf {
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+}
+
+impl BuildTemplate for Protobuf {
+    #[inline(always)]
+    fn get_templates() -> &'static [(&'static str, &'static str)] {
+        PROTOBUF_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        PROTOBUF_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        let template_name = match with_risk {
+            true => "proto.risk",
+            false => "proto.hazards",
+        };
+
+        vec![OutputFile::new(OUTPUT_FILE, template_name)]
+    }
+}
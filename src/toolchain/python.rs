@@ -0,0 +1,43 @@
+use crate::{BuildTemplate, OutputFile};
+
+use super::builtin_templates;
+
+const OUTPUT_FILE: &str = "hazards.py";
+
+static PYTHON_TEMPLATES: &[(&str, &str)] = &builtin_templates!["python" =>
+    ("py.hazards", "hazards.py")
+];
+
+static PYTHON_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["python" =>
+    ("py.risk", "risk.py")
+];
+
+pub(crate) struct Python;
+
+impl Python {
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+}
+
+impl BuildTemplate for Python {
+    #[inline(always)]
+    fn get_templates() -> &'static [(&'static str, &'static str)] {
+        PYTHON_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        PYTHON_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        let template_name = match with_risk {
+            true => "py.risk",
+            false => "py.hazards",
+        };
+
+        vec![OutputFile::new(OUTPUT_FILE, template_name)]
+    }
+}
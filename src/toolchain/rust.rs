@@ -1,36 +1,93 @@
-use std::path::Path;
+use std::collections::HashMap;
 
-use crate::{BuildTemplate, OutputFile};
+use minijinja::Value;
+
+use crate::{BuildTemplate, OutputFile, Profile, SerdeRepresentation};
 
 use super::builtin_templates;
 
 const OUTPUT_FILE: &str = "ontology.rs";
 const OUTPUT_WITH_RISK_FILE: &str = "ontology_with_risk.rs";
 
+const OUTPUT_MANIFEST_FILE: &str = "Cargo.toml";
+const OUTPUT_LIB_FILE: &str = "src/lib.rs";
+const OUTPUT_CRATE_ONTOLOGY_FILE: &str = "src/ontology.rs";
+
 static RUST_TEMPLATES: &[(&str, &str)] = &builtin_templates!["rust" =>
     ("rs.ontology", "ontology.rs"),
+    ("rs.cargo", "manifest.toml"),
+    ("rs.lib", "lib.rs")
+];
+
+static RUST_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["rust" =>
     ("rs.risk", "risk.rs")
 ];
 
-pub(crate) struct Rust;
+pub(crate) struct Rust<'a> {
+    crate_name: Option<&'a str>,
+    profile: Profile,
+    serde_representation: SerdeRepresentation,
+}
 
-impl Rust {
-    pub(crate) const fn new() -> Self {
-        Self
+impl<'a> Rust<'a> {
+    pub(crate) const fn new(
+        crate_name: Option<&'a str>,
+        profile: Profile,
+        serde_representation: SerdeRepresentation,
+    ) -> Self {
+        Self {
+            crate_name,
+            profile,
+            serde_representation,
+        }
     }
 }
 
-impl BuildTemplate for Rust {
+impl<'a> BuildTemplate for Rust<'a> {
     #[inline(always)]
     fn get_templates() -> &'static [(&'static str, &'static str)] {
         RUST_TEMPLATES
     }
 
     #[inline(always)]
-    fn output_file(output_path: &Path, with_risk: bool) -> OutputFile {
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        RUST_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        let template_name = match with_risk {
+            true => "rs.risk",
+            false => "rs.ontology",
+        };
+
+        // A crate contains the manifest, the library root and
+        // the ontology module.
+        if self.crate_name.is_some() {
+            return vec![
+                OutputFile::new(OUTPUT_MANIFEST_FILE, "rs.cargo"),
+                OutputFile::new(OUTPUT_LIB_FILE, "rs.lib"),
+                OutputFile::new(OUTPUT_CRATE_ONTOLOGY_FILE, template_name),
+            ];
+        }
+
         match with_risk {
-            true => OutputFile::new(output_path.join(OUTPUT_WITH_RISK_FILE), "rs.risk"),
-            false => OutputFile::new(output_path.join(OUTPUT_FILE), "rs.ontology"),
+            true => vec![OutputFile::new(OUTPUT_WITH_RISK_FILE, template_name)],
+            false => vec![OutputFile::new(OUTPUT_FILE, template_name)],
         }
     }
+
+    fn extend_context(&self, context: &mut HashMap<&'static str, Value>) {
+        // Add the name of the generated crate.
+        context.insert("crate_name", Value::from(self.crate_name));
+
+        // Add the profile which defines the collection backend.
+        context.insert("rust_profile", Value::from(self.profile.name()));
+
+        // Add the serde representation of hazards and categories.
+        context.insert(
+            "serde_representation",
+            Value::from(self.serde_representation.name()),
+        );
+    }
 }
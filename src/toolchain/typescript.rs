@@ -0,0 +1,43 @@
+use crate::{BuildTemplate, OutputFile};
+
+use super::builtin_templates;
+
+const OUTPUT_FILE: &str = "hazards.ts";
+
+static TYPESCRIPT_TEMPLATES: &[(&str, &str)] = &builtin_templates!["typescript" =>
+    ("ts.hazards", "hazards.ts")
+];
+
+static TYPESCRIPT_RISK_TEMPLATES: &[(&str, &str)] = &builtin_templates!["typescript" =>
+    ("ts.risk", "risk.ts")
+];
+
+pub(crate) struct TypeScript;
+
+impl TypeScript {
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+}
+
+impl BuildTemplate for TypeScript {
+    #[inline(always)]
+    fn get_templates() -> &'static [(&'static str, &'static str)] {
+        TYPESCRIPT_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn get_risk_templates() -> &'static [(&'static str, &'static str)] {
+        TYPESCRIPT_RISK_TEMPLATES
+    }
+
+    #[inline(always)]
+    fn output_files(&self, with_risk: bool) -> Vec<OutputFile> {
+        let template_name = match with_risk {
+            true => "ts.risk",
+            false => "ts.hazards",
+        };
+
+        vec![OutputFile::new(OUTPUT_FILE, template_name)]
+    }
+}
@@ -0,0 +1,209 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    model::RiskScoreRange,
+    toolchain::{
+        category_iri, identifier, iri, is_category, is_hazard, label, object_type, risk_score_value,
+    },
+    Ontology,
+};
+
+/// Kinds of problems found in an ontology.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueKind {
+    /// A hazard or a category has no label.
+    MissingLabel,
+    /// A hazard has no category.
+    MissingCategory,
+    /// A hazard refers to a category that is not declared.
+    UndeclaredCategory(String),
+    /// A hazard or a category has the same label of another node.
+    DuplicateLabel {
+        /// The duplicated label.
+        label: String,
+        /// The `@id` of the node which first defines the label.
+        first: String,
+    },
+    /// A label is not a valid identifier.
+    InvalidLabel(String),
+    /// An explicit hazard identifier is not an integer between 0 and 65535.
+    InvalidIdentifier(String),
+    /// A bound of the risk score range is declared more than once.
+    DuplicateRiskScoreRange,
+    /// A risk score is outside the declared range.
+    RiskScoreOutOfRange {
+        /// The risk score.
+        risk_score: i64,
+        /// The inclusive lower bound of the range.
+        min: u8,
+        /// The inclusive upper bound of the range.
+        max: u8,
+    },
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingLabel => write!(f, "missing label"),
+            Self::MissingCategory => write!(f, "missing category"),
+            Self::UndeclaredCategory(category) => {
+                write!(f, "category {category} is not declared")
+            }
+            Self::DuplicateLabel { label, first } => {
+                write!(f, "label {label:?} is already defined by {first}")
+            }
+            Self::InvalidLabel(label) => {
+                write!(f, "label {label:?} is not a valid identifier")
+            }
+            Self::InvalidIdentifier(identifier) => {
+                write!(
+                    f,
+                    "identifier {identifier} is not an integer between 0 and 65535"
+                )
+            }
+            Self::DuplicateRiskScoreRange => {
+                write!(f, "risk score range is already declared")
+            }
+            Self::RiskScoreOutOfRange {
+                risk_score,
+                min,
+                max,
+            } => write!(
+                f,
+                "risk score {risk_score} is outside the {min}..={max} range"
+            ),
+        }
+    }
+}
+
+/// A problem found in an ontology, together with the `@id`
+/// of the node which causes it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    id: String,
+    kind: IssueKind,
+}
+
+impl ValidationIssue {
+    fn new(id: &str, kind: IssueKind) -> Self {
+        Self {
+            id: id.to_owned(),
+            kind,
+        }
+    }
+
+    /// Returns the `@id` of the node which causes the problem.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the kind of problem.
+    pub fn kind(&self) -> &IssueKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.id, self.kind)
+    }
+}
+
+// Checks whether a label can be used as an identifier in all the
+// supported languages.
+fn is_valid_identifier(label: &str) -> bool {
+    let mut chars = label.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric())
+}
+
+// Checks the structure of an ontology.
+pub(crate) fn validate(ontology: &Ontology) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    // Retrieve the declared categories.
+    let declared_categories: Vec<&str> = ontology
+        .iter()
+        .filter(|object| object_type(object).is_some_and(is_category))
+        .map(iri)
+        .collect();
+
+    // Retrieve the risk score range, as done when generating the APIs.
+    let (risk_score_range, duplicates) = RiskScoreRange::extract(ontology);
+    for id in duplicates {
+        issues.push(ValidationIssue::new(id, IssueKind::DuplicateRiskScoreRange));
+    }
+    let (min, max) = risk_score_range.bounds();
+
+    // Labels are checked separately for hazards and categories.
+    let mut labels: HashMap<(bool, &str), &str> = HashMap::new();
+
+    for object in ontology {
+        let Some(object_type) = object_type(object) else {
+            continue;
+        };
+        let id = iri(object);
+        let is_hazard = is_hazard(object_type);
+
+        // Check the label.
+        let label = label(object);
+        if label.is_empty() {
+            issues.push(ValidationIssue::new(id, IssueKind::MissingLabel));
+        } else if !is_valid_identifier(label) {
+            issues.push(ValidationIssue::new(
+                id,
+                IssueKind::InvalidLabel(label.to_owned()),
+            ));
+        } else if let Some(first) = labels.get(&(is_hazard, label)) {
+            issues.push(ValidationIssue::new(
+                id,
+                IssueKind::DuplicateLabel {
+                    label: label.to_owned(),
+                    first: (*first).to_owned(),
+                },
+            ));
+        } else {
+            labels.insert((is_hazard, label), id);
+        }
+
+        if !is_hazard {
+            continue;
+        }
+
+        // Check the category.
+        match category_iri(object) {
+            None => issues.push(ValidationIssue::new(id, IssueKind::MissingCategory)),
+            Some(category) if !declared_categories.contains(&category) => {
+                issues.push(ValidationIssue::new(
+                    id,
+                    IssueKind::UndeclaredCategory(category.to_owned()),
+                ));
+            }
+            Some(_) => {}
+        }
+
+        // Check the explicit identifier.
+        if let Err(identifier) = identifier(object) {
+            issues.push(ValidationIssue::new(
+                id,
+                IssueKind::InvalidIdentifier(identifier),
+            ));
+        }
+
+        // Check the risk score.
+        if let Some(risk_score) = risk_score_value(object)
+            .filter(|risk_score| !(i64::from(min)..=i64::from(max)).contains(risk_score))
+        {
+            issues.push(ValidationIssue::new(
+                id,
+                IssueKind::RiskScoreOutOfRange {
+                    risk_score,
+                    min,
+                    max,
+                },
+            ));
+        }
+    }
+
+    issues
+}
@@ -0,0 +1,91 @@
+#include "hazards.h"
+
+#include <stddef.h>
+
+const char *hazard_name(hazard_t hazard)
+{
+    switch (hazard) {
+{%- for hazard in hazards %}
+    case HAZARD_{{ hazard.name|camelcase_to_snakecase|upper }}:
+        return "{{ hazard.name|camelcase_to_whitespaces }}";
+{%- endfor %}
+    case HAZARD_INVALID:
+        break;
+    }
+
+    return NULL;
+}
+
+const char *hazard_description(hazard_t hazard)
+{
+    switch (hazard) {
+{%- for hazard in hazards %}
+    case HAZARD_{{ hazard.name|camelcase_to_snakecase|upper }}:
+        return "{{ hazard.description }}.";
+{%- endfor %}
+    case HAZARD_INVALID:
+        break;
+    }
+
+    return NULL;
+}
+
+category_t hazard_category(hazard_t hazard)
+{
+    switch (hazard) {
+{%- for hazard in hazards %}
+    case HAZARD_{{ hazard.name|camelcase_to_snakecase|upper }}:
+        return CATEGORY_{{ hazard.category|camelcase_to_snakecase|upper }};
+{%- endfor %}
+    case HAZARD_INVALID:
+        break;
+    }
+
+    return CATEGORY_INVALID;
+}
+
+bool hazard_from_id(uint16_t id, hazard_t *hazard)
+{
+{%- if not hazards %}
+    /* No hazard exists. */
+    (void)hazard;
+
+{% endif %}    switch (id) {
+{%- for hazard in hazards %}
+    case {{ hazard.id }}:
+        *hazard = HAZARD_{{ hazard.name|camelcase_to_snakecase|upper }};
+        return true;
+{%- endfor %}
+    default:
+        return false;
+    }
+}
+{%- block risk_definitions %}{% endblock %}
+
+const char *category_name(category_t category)
+{
+    switch (category) {
+{%- for category in categories %}
+    case CATEGORY_{{ category.name|camelcase_to_snakecase|upper }}:
+        return "{{ category.name|camelcase_to_whitespaces }}";
+{%- endfor %}
+    case CATEGORY_INVALID:
+        break;
+    }
+
+    return NULL;
+}
+
+const char *category_description(category_t category)
+{
+    switch (category) {
+{%- for category in categories %}
+    case CATEGORY_{{ category.name|camelcase_to_snakecase|upper }}:
+        return "{{ category.description }}";
+{%- endfor %}
+    case CATEGORY_INVALID:
+        break;
+    }
+
+    return NULL;
+}
@@ -0,0 +1,63 @@
+#ifndef HAZARDS_H
+#define HAZARDS_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/** All possible hazards for a device task. */
+typedef enum {
+{%- for hazard in hazards %}
+    /** {{ hazard.description }}. */
+    HAZARD_{{ hazard.name|camelcase_to_snakecase|upper }} = {{ hazard.id }},
+{%- endfor %}
+    /** Not a hazard, never returned by the APIs. */
+    HAZARD_INVALID = -1,
+} hazard_t;
+
+/** Hazard categories. */
+typedef enum {
+{%- for category in categories %}
+    /** {{ category.description }} */
+    CATEGORY_{{ category.name|camelcase_to_snakecase|upper }} = {{ loop.index0 }},
+{%- endfor %}
+    /** Not a category, returned for invalid hazards. */
+    CATEGORY_INVALID = -1,
+} category_t;
+
+/** Returns a hazard name. */
+const char *hazard_name(hazard_t hazard);
+
+/** Returns a hazard description. */
+const char *hazard_description(hazard_t hazard);
+
+/**
+ * Returns the category associated with a hazard.
+ *
+ * A hazard **must** be associated with **only** one category.
+ */
+category_t hazard_category(hazard_t hazard);
+
+/**
+ * Retrieves a hazard from an integer identifier.
+ *
+ * Returns false whenever the identifier does not exist or
+ * it is not correct.
+ */
+bool hazard_from_id(uint16_t id, hazard_t *hazard);
+{%- block risk_declarations %}{% endblock %}
+
+/** Returns a category name. */
+const char *category_name(category_t category);
+
+/** Returns a category description. */
+const char *category_description(category_t category);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HAZARDS_H */
@@ -0,0 +1,27 @@
+{% extends "c.source" %}
+
+{%- block risk_definitions %}
+
+bool hazard_risk_score(hazard_t hazard, uint8_t *risk_score)
+{
+{%- if hazards|rejectattr("risk_score", "none")|list|length == 0 %}
+    /* No hazard has a risk score. */
+    (void)risk_score;
+{% endif %}
+    switch (hazard) {
+{%- for hazard in hazards %}
+    case HAZARD_{{ hazard.name|camelcase_to_snakecase|upper }}:
+{%- if hazard.risk_score == none %}
+        return false;
+{%- else %}
+        *risk_score = {{ hazard.risk_score }};
+        return true;
+{%- endif %}
+{%- endfor %}
+    case HAZARD_INVALID:
+        break;
+    }
+
+    return false;
+}
+{%- endblock %}
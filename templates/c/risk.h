@@ -0,0 +1,11 @@
+{% extends "c.header" %}
+
+{%- block risk_declarations %}
+
+/**
+ * Retrieves a hazard risk score.
+ *
+ * Returns false whenever the hazard has no risk score.
+ */
+bool hazard_risk_score(hazard_t hazard, uint8_t *risk_score);
+{%- endblock %}
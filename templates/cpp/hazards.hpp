@@ -0,0 +1,162 @@
+#ifndef HAZARDS_HPP
+#define HAZARDS_HPP
+
+#include <bitset>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <string_view>
+
+namespace hazards {
+
+/// Number of hazards.
+constexpr std::size_t HAZARDS_COUNT = {{ hazards|length }};
+
+/// Greatest hazard identifier.
+constexpr std::uint16_t MAX_HAZARD_ID = {{ hazards|map(attribute="id")|max|default(0) }};
+
+/// All possible hazards for a device task.
+enum class Hazard : std::uint16_t {
+{%- for hazard in hazards %}
+    /// {{ hazard.description }}.
+    {{ hazard.name }} = {{ hazard.id }},
+{%- endfor %}
+};
+
+/// Hazard categories.
+enum class Category : std::uint16_t {
+{%- for category in categories %}
+    /// {{ category.description }}
+    {{ category.name }} = {{ loop.index0 }},
+{%- endfor %}
+};
+
+/// Returns a `Hazard` name.
+constexpr std::string_view name(Hazard hazard) noexcept {
+    switch (hazard) {
+{%- for hazard in hazards %}
+    case Hazard::{{ hazard.name }}:
+        return "{{ hazard.name|camelcase_to_whitespaces }}";
+{%- endfor %}
+    }
+
+    return {};
+}
+
+/// Returns a `Hazard` description.
+constexpr std::string_view description(Hazard hazard) noexcept {
+    switch (hazard) {
+{%- for hazard in hazards %}
+    case Hazard::{{ hazard.name }}:
+        return "{{ hazard.description }}.";
+{%- endfor %}
+    }
+
+    return {};
+}
+
+/// Returns the `Category` associated with a `Hazard`.
+///
+/// A hazard **must** be associated with **only** one category.
+constexpr Category category(Hazard hazard) noexcept {
+    switch (hazard) {
+{%- for hazard in hazards %}
+    case Hazard::{{ hazard.name }}:
+        return Category::{{ hazard.category }};
+{%- endfor %}
+    }
+
+    // Invalid hazard.
+    return static_cast<Category>(-1);
+}
+
+/// Returns the identifier associated with a `Hazard`.
+constexpr std::uint16_t id(Hazard hazard) noexcept {
+    return static_cast<std::uint16_t>(hazard);
+}
+{%- block fn_risk %}{% endblock %}
+
+/// Returns a `Hazard` from an integer identifier.
+///
+/// The value is `std::nullopt` whenever the identifier does not exist or
+/// it is not correct.
+constexpr std::optional<Hazard> from_id(std::uint16_t id) noexcept {
+    switch (id) {
+{%- for hazard in hazards %}
+    case {{ hazard.id }}:
+        return Hazard::{{ hazard.name }};
+{%- endfor %}
+    default:
+        return std::nullopt;
+    }
+}
+
+/// Returns a `Category` name.
+constexpr std::string_view name(Category category) noexcept {
+    switch (category) {
+{%- for category in categories %}
+    case Category::{{ category.name }}:
+        return "{{ category.name|camelcase_to_whitespaces }}";
+{%- endfor %}
+    }
+
+    return {};
+}
+
+/// Returns a `Category` description.
+constexpr std::string_view description(Category category) noexcept {
+    switch (category) {
+{%- for category in categories %}
+    case Category::{{ category.name }}:
+        return "{{ category.description }}";
+{%- endfor %}
+    }
+
+    return {};
+}
+
+/// A collection of `Hazard`s.
+class Hazards {
+public:
+    /// Initializes a new empty `Hazards` collection.
+    Hazards() noexcept = default;
+
+    /// Adds a new `Hazard` to the `Hazards` collection.
+    void add(Hazard hazard) noexcept {
+        bits_.set(id(hazard));
+    }
+
+    /// Whether the `Hazards` collection is empty.
+    bool is_empty() const noexcept {
+        return bits_.none();
+    }
+
+    /// Returns the number of `Hazard`s contained into the `Hazards` collection.
+    std::size_t size() const noexcept {
+        return bits_.count();
+    }
+
+    /// Checks whether a `Hazard` is contained into
+    /// the `Hazards` collection.
+    bool contains(Hazard hazard) const noexcept {
+        return bits_.test(id(hazard));
+    }
+
+    /// Calls a function on each `Hazard` contained into
+    /// the `Hazards` collection.
+    template <typename F>
+    void for_each(F &&f) const {
+        for (std::size_t i = 0; i < bits_.size(); ++i) {
+            if (bits_.test(i)) {
+                f(*from_id(static_cast<std::uint16_t>(i)));
+            }
+        }
+    }
+
+private:
+    std::bitset<MAX_HAZARD_ID + 1> bits_;
+};
+
+} // namespace hazards
+
+#endif // HAZARDS_HPP
@@ -0,0 +1,18 @@
+{% extends "cpp.hazards" %}
+
+{%- block fn_risk %}
+
+/// Returns a `Hazard` risk score.
+///
+/// The value is `std::nullopt` whenever the hazard has no risk score.
+constexpr std::optional<std::uint8_t> risk_score(Hazard hazard) noexcept {
+    switch (hazard) {
+{%- for hazard in hazards %}
+    case Hazard::{{ hazard.name }}:
+        return {% if hazard.risk_score == none %}std::nullopt{% else %}{{ hazard.risk_score }}{% endif %};
+{%- endfor %}
+    }
+
+    return std::nullopt;
+}
+{%- endblock %}
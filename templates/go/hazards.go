@@ -0,0 +1,162 @@
+// Package hazards defines all possible hazards for a device task.
+package hazards
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+// Hazard is a possible hazard for a device task.
+type Hazard uint16
+
+const (
+{%- for hazard in hazards %}
+{%- if not loop.first %}
+{% endif %}
+	// {{ hazard.name }}: {{ hazard.description }}.
+	{{ hazard.name }} Hazard = {{ hazard.id }}
+{%- endfor %}
+)
+
+// Category is a hazard category.
+type Category uint16
+
+const (
+{%- for category in categories %}
+{%- if not loop.first %}
+{% endif %}
+	// Category{{ category.name }}: {{ category.description }}
+	Category{{ category.name }} Category = {{ loop.index0 }}
+{%- endfor %}
+)
+
+// String returns a Hazard name.
+func (h Hazard) String() string {
+	switch h {
+{%- for hazard in hazards %}
+	case {{ hazard.name }}:
+		return "{{ hazard.name|camelcase_to_whitespaces }}"
+{%- endfor %}
+	}
+
+	return fmt.Sprintf("Hazard(%d)", uint16(h))
+}
+
+// Description returns a Hazard description.
+func (h Hazard) Description() string {
+	switch h {
+{%- for hazard in hazards %}
+	case {{ hazard.name }}:
+		return "{{ hazard.description }}."
+{%- endfor %}
+	}
+
+	return ""
+}
+
+// Category returns the Category associated with a Hazard.
+//
+// A hazard must be associated with only one category.
+func (h Hazard) Category() Category {
+	switch h {
+{%- for hazard in hazards %}
+	case {{ hazard.name }}:
+		return Category{{ hazard.category }}
+{%- endfor %}
+	}
+
+	// Invalid hazard.
+	return Category(^uint16(0))
+}
+
+// ID returns the identifier associated with a Hazard.
+func (h Hazard) ID() uint16 {
+	return uint16(h)
+}
+{%- block fn_risk %}{% endblock %}
+
+// FromID returns a Hazard from an integer identifier.
+//
+// The boolean value is false whenever the identifier does not exist or
+// it is not correct.
+func FromID(id uint16) (Hazard, bool) {
+	switch id {
+{%- for hazard in hazards %}
+	case {{ hazard.id }}:
+		return {{ hazard.name }}, true
+{%- endfor %}
+	}
+
+	return 0, false
+}
+
+// MarshalJSON encodes a Hazard as its ontology label.
+func (h Hazard) MarshalJSON() ([]byte, error) {
+	switch h {
+{%- for hazard in hazards %}
+	case {{ hazard.name }}:
+		return json.Marshal("{{ hazard.name }}")
+{%- endfor %}
+	}
+
+	return nil, fmt.Errorf("hazards: invalid hazard %d", uint16(h))
+}
+
+// UnmarshalJSON decodes a Hazard from its ontology label.
+func (h *Hazard) UnmarshalJSON(data []byte) error {
+	var label string
+	if err := json.Unmarshal(data, &label); err != nil {
+		return err
+	}
+
+	switch label {
+{%- for hazard in hazards %}
+	case "{{ hazard.name }}":
+		*h = {{ hazard.name }}
+{%- endfor %}
+	default:
+		return fmt.Errorf("hazards: unknown hazard %q", label)
+	}
+
+	return nil
+}
+
+// String returns a Category name.
+func (c Category) String() string {
+	switch c {
+{%- for category in categories %}
+	case Category{{ category.name }}:
+		return "{{ category.name|camelcase_to_whitespaces }}"
+{%- endfor %}
+	}
+
+	return fmt.Sprintf("Category(%d)", uint16(c))
+}
+
+// Description returns a Category description.
+func (c Category) Description() string {
+	switch c {
+{%- for category in categories %}
+	case Category{{ category.name }}:
+		return "{{ category.description }}"
+{%- endfor %}
+	}
+
+	return ""
+}
+
+// Hazards returns all Hazards associated with a Category.
+func (c Category) Hazards() []Hazard {
+	switch c {
+{%- for category in categories %}
+	case Category{{ category.name }}:
+		return []Hazard{
+		{%- for hazard in category.hazards %}
+			{{ hazard }},
+		{%- endfor %}
+		}
+{%- endfor %}
+	}
+
+	return nil
+}
@@ -0,0 +1,18 @@
+{% extends "go.hazards" %}
+
+{%- block fn_risk %}
+
+// RiskScore returns a Hazard risk score.
+//
+// The boolean value is false whenever the hazard has no risk score.
+func (h Hazard) RiskScore() (uint8, bool) {
+	switch h {
+{%- for hazard in hazards %}
+	case {{ hazard.name }}:
+		return {% if hazard.risk_score == none %}0, false{% else %}{{ hazard.risk_score }}, true{% endif %}
+{%- endfor %}
+	}
+
+	return 0, false
+}
+{%- endblock %}
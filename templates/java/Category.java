@@ -0,0 +1,48 @@
+{%- if package %}package {{ package }};
+
+{% endif -%}
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+
+/** Hazard categories. */
+public enum Category {
+{%- for category in categories %}
+    /** {{ category.description }} */
+    {{ category.name|camelcase_to_snakecase|upper }}("{{ category.name|camelcase_to_whitespaces }}", "{{ category.description }}"){% if loop.last %};{% else %},{% endif %}
+{%- endfor %}
+
+    private final String name;
+    private final String description;
+
+    Category(String name, String description) {
+        this.name = name;
+        this.description = description;
+    }
+
+    /** Returns a {@link Category} name. */
+    public String getName() {
+        return name;
+    }
+
+    /** Returns a {@link Category} description. */
+    public String getDescription() {
+        return description;
+    }
+
+    /** Returns all {@link Hazard}s associated with a {@link Category}. */
+    public List<Hazard> getHazards() {
+        List<Hazard> hazards = new ArrayList<>();
+        for (Hazard hazard : Hazard.values()) {
+            if (hazard.getCategory() == this) {
+                hazards.add(hazard);
+            }
+        }
+        return Collections.unmodifiableList(hazards);
+    }
+
+    @Override
+    public String toString() {
+        return name;
+    }
+}
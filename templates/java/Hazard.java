@@ -0,0 +1,71 @@
+{%- if package %}package {{ package }};
+
+{% endif -%}
+/** All possible hazards for a device task. */
+public enum Hazard {
+{%- for hazard in hazards %}
+    /** {{ hazard.description }}. */
+    {{ hazard.name|camelcase_to_snakecase|upper }}({{ hazard.id }}, "{{ hazard.name|camelcase_to_whitespaces }}", "{{ hazard.description }}.", Category.{{ hazard.category|camelcase_to_snakecase|upper }}{% block constant_risk scoped %}{% endblock %}){% if loop.last %};{% else %},{% endif %}
+{%- endfor %}
+
+    private final int id;
+    private final String name;
+    private final String description;
+    private final Category category;
+    {%- block field_risk %}{% endblock %}
+
+    Hazard(int id, String name, String description, Category category{% block param_risk %}{% endblock %}) {
+        this.id = id;
+        this.name = name;
+        this.description = description;
+        this.category = category;
+        {%- block assign_risk %}{% endblock %}
+    }
+
+    /** Returns the identifier associated with a {@link Hazard}. */
+    public int getId() {
+        return id;
+    }
+
+    /** Returns a {@link Hazard} name. */
+    public String getName() {
+        return name;
+    }
+
+    /** Returns a {@link Hazard} description. */
+    public String getDescription() {
+        return description;
+    }
+
+    /**
+     * Returns the {@link Category} associated with a {@link Hazard}.
+     *
+     * <p>A hazard <b>must</b> be associated with <b>only</b> one category.
+     */
+    public Category getCategory() {
+        return category;
+    }
+    {%- block fn_risk %}{% endblock %}
+
+    /**
+     * Returns a {@link Hazard} from an integer identifier.
+     *
+     * <p>The value is {@code null} whenever the identifier does not exist or
+     * it is not correct.
+     */
+    public static Hazard fromId(int id) {
+        switch (id) {
+        {%- for hazard in hazards %}
+            case {{ hazard.id }}:
+                return {{ hazard.name|camelcase_to_snakecase|upper }};
+        {%- endfor %}
+            default:
+                return null;
+        }
+    }
+
+    @Override
+    public String toString() {
+        return name;
+    }
+}
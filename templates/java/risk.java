@@ -0,0 +1,25 @@
+{% extends "java.hazard" %}
+
+{%- block constant_risk %}, {% if hazard.risk_score == none %}null{% else %}{{ hazard.risk_score }}{% endif %}{% endblock %}
+
+{%- block field_risk %}
+    private final Integer riskScore;
+{%- endblock %}
+
+{%- block param_risk %}, Integer riskScore{% endblock %}
+
+{%- block assign_risk %}
+        this.riskScore = riskScore;
+{%- endblock %}
+
+{%- block fn_risk %}
+
+    /**
+     * Returns a {@link Hazard} risk score.
+     *
+     * <p>The value is {@code null} whenever the hazard has no risk score.
+     */
+    public Integer getRiskScore() {
+        return riskScore;
+    }
+{%- endblock %}
@@ -0,0 +1,115 @@
+"""All possible hazards for a device task and their categories."""
+
+from __future__ import annotations
+
+from enum import Enum, IntEnum
+from typing import Dict, Optional, Tuple
+
+__all__ = ["Category", "Hazard"]
+
+
+class Category(Enum):
+    """Hazard categories."""
+{% for category in categories %}
+    {{ category.name|camelcase_to_snakecase|upper }} = "{{ category.name }}"
+    """{{ category.description }}"""
+{% endfor %}
+    @property
+    def display_name(self) -> str:
+        """Returns a `Category` human-readable name."""
+        return _CATEGORY_DISPLAY_NAMES[self]
+
+    @property
+    def description(self) -> str:
+        """Returns a `Category` description."""
+        return _CATEGORY_DESCRIPTIONS[self]
+
+    @property
+    def hazards(self) -> Tuple[Hazard, ...]:
+        """Returns all `Hazard`s associated with a `Category`."""
+        return _CATEGORY_HAZARDS[self]
+
+
+class Hazard(IntEnum):
+    """All possible hazards for a device task."""
+{% for hazard in hazards %}
+    {{ hazard.name|camelcase_to_snakecase|upper }} = {{ hazard.id }}
+    """{{ hazard.description }}."""
+{% endfor %}
+    @property
+    def display_name(self) -> str:
+        """Returns a `Hazard` human-readable name."""
+        return _HAZARD_DISPLAY_NAMES[self]
+
+    @property
+    def description(self) -> str:
+        """Returns a `Hazard` description."""
+        return _HAZARD_DESCRIPTIONS[self]
+
+    @property
+    def category(self) -> Category:
+        """Returns the `Category` associated with a `Hazard`.
+
+        A hazard **must** be associated with **only** one category.
+        """
+        return _HAZARD_CATEGORIES[self]
+
+    @property
+    def id(self) -> int:
+        """Returns the identifier associated with a `Hazard`."""
+        return int(self)
+{%- block fn_risk %}{% endblock %}
+
+    @classmethod
+    def from_id(cls, id: int) -> Optional[Hazard]:
+        """Returns a `Hazard` from an integer identifier.
+
+        The value is `None` whenever the identifier does not exist or
+        it is not correct.
+        """
+        try:
+            return cls(id)
+        except ValueError:
+            return None
+
+
+_CATEGORY_DISPLAY_NAMES: Dict[Category, str] = {
+{%- for category in categories %}
+    Category.{{ category.name|camelcase_to_snakecase|upper }}: "{{ category.name|camelcase_to_whitespaces }}",
+{%- endfor %}
+}
+
+_CATEGORY_DESCRIPTIONS: Dict[Category, str] = {
+{%- for category in categories %}
+    Category.{{ category.name|camelcase_to_snakecase|upper }}: "{{ category.description }}",
+{%- endfor %}
+}
+
+_CATEGORY_HAZARDS: Dict[Category, Tuple[Hazard, ...]] = {
+{%- for category in categories %}
+    Category.{{ category.name|camelcase_to_snakecase|upper }}: (
+    {%- for hazard in category.hazards %}
+        Hazard.{{ hazard|camelcase_to_snakecase|upper }},
+    {%- endfor %}
+    ),
+{%- endfor %}
+}
+
+_HAZARD_DISPLAY_NAMES: Dict[Hazard, str] = {
+{%- for hazard in hazards %}
+    Hazard.{{ hazard.name|camelcase_to_snakecase|upper }}: "{{ hazard.name|camelcase_to_whitespaces }}",
+{%- endfor %}
+}
+
+_HAZARD_DESCRIPTIONS: Dict[Hazard, str] = {
+{%- for hazard in hazards %}
+    Hazard.{{ hazard.name|camelcase_to_snakecase|upper }}: "{{ hazard.description }}.",
+{%- endfor %}
+}
+
+_HAZARD_CATEGORIES: Dict[Hazard, Category] = {
+{%- for hazard in hazards %}
+    Hazard.{{ hazard.name|camelcase_to_snakecase|upper }}: Category.{{ hazard.category|camelcase_to_snakecase|upper }},
+{%- endfor %}
+}
+{%- block table_risk %}{% endblock %}
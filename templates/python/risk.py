@@ -0,0 +1,21 @@
+{% extends "py.hazards" %}
+
+{%- block fn_risk %}
+
+    @property
+    def risk_score(self) -> Optional[int]:
+        """Returns a `Hazard` risk score.
+
+        The value is `None` whenever the hazard has no risk score.
+        """
+        return _HAZARD_RISK_SCORES[self]
+{%- endblock %}
+
+{%- block table_risk %}
+
+_HAZARD_RISK_SCORES: Dict[Hazard, Optional[int]] = {
+{%- for hazard in hazards %}
+    Hazard.{{ hazard.name|camelcase_to_snakecase|upper }}: {% if hazard.risk_score == none %}None{% else %}{{ hazard.risk_score }}{% endif %},
+{%- endfor %}
+}
+{%- endblock %}
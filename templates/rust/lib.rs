@@ -0,0 +1,22 @@
+//! Hazards APIs generated from the hazard ontology.
+{%- if rust_profile == "no-std" %}
+
+#![no_std]
+
+/// Maximum number of elements in a [`HazardsData`] collection.
+///
+/// It is the number of hazards rounded up to the next power of two,
+/// as required by [`heapless::FnvIndexSet`].
+pub const MAXIMUM_ELEMENTS: usize = {{ hazards|length|next_power_of_two }};
+{%- elif rust_profile == "alloc" %}
+
+#![no_std]
+
+extern crate alloc;
+{%- endif %}
+
+// The data types implement `PartialEq` on top of a derived `Hash`.
+#[allow(clippy::derived_hash_with_manual_eq)]
+mod ontology;
+
+pub use ontology::*;
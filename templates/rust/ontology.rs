@@ -1,11 +1,92 @@
+{#- Serde implementations of an enum, represented by its identifier,
+    its label or its IRI. String representations accept all the strings
+    parsed by `FromStr`. #}
+{%- macro serde_impls(type, representation, expecting) %}
+#[cfg(feature = "serde")]
+impl Serialize for {{ type }} {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    {%- if representation == "id" %}
+        serializer.serialize_u16(self.id())
+    {%- elif representation == "iri" %}
+        serializer.serialize_str(self.iri())
+    {%- else %}
+        serializer.serialize_str(self.label())
+    {%- endif %}
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for {{ type }} {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = {{ type }};
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("{{ expecting }}")
+            }
+        {%- if representation == "id" %}
+
+            fn visit_u64<E: serde::de::Error>(self, id: u64) -> Result<Self::Value, E> {
+                u16::try_from(id)
+                    .ok()
+                    .and_then({{ type }}::from_id)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(id), &self))
+            }
+        }
+
+        deserializer.deserialize_u16(Visitor)
+        {%- else %}
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value
+                    .parse()
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+        {%- endif %}
+    }
+}
+{%- endmacro %}
+{#- The width of the hazards bitmask depends on the highest identifier. #}
+{%- set max_id = hazards|map(attribute="id")|max|default(0) %}
+{%- if max_id < 32 %}
+{%- set bits = "u32" %}
+{%- elif max_id < 64 %}
+{%- set bits = "u64" %}
+{%- else %}
+{%- set words = max_id // 64 + 1 %}
+{%- endif %}
+{%- if rust_profile == "std" %}
+{%- set set_type = "HashSet" %}
+{%- set iter_type = "Iter" %}
+{%- set capacity = "" -%}
+use std::collections::{hash_set::Iter, HashSet};
+{%- elif rust_profile == "alloc" %}
+{%- set set_type = "BTreeSet" %}
+{%- set iter_type = "Iter" %}
+{%- set capacity = "" -%}
+use alloc::collections::{btree_set::Iter, BTreeSet};
+{%- else %}
+{%- set set_type = "FnvIndexSet" %}
+{%- set iter_type = "IndexSetIter" %}
+{%- set capacity = ", MAXIMUM_ELEMENTS" -%}
 use heapless::{FnvIndexSet, IndexSetIter};
+{%- endif %}
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+{%- if rust_profile == "no-std" %}
 
 use crate::MAXIMUM_ELEMENTS;
+{%- endif %}
 
 /// Hazard data.
-#[derive(Debug, Clone, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HazardData<'a> {
     /// Identifier.
     pub id: u16,
@@ -35,15 +116,32 @@ impl<'a> core::cmp::PartialEq for HazardData<'a> {
         self.id == other.id
     }
 }
+{%- if rust_profile == "alloc" %}
+
+impl<'a> core::cmp::PartialOrd for HazardData<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> core::cmp::Ord for HazardData<'a> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+{%- endif %}
 
 /// A collection of [`HazardData`]s.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct HazardsData<'a>(#[serde(borrow)] FnvIndexSet<HazardData<'a>, MAXIMUM_ELEMENTS>);
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HazardsData<'a>(
+    #[cfg_attr(feature = "serde", serde(borrow))] {{ set_type }}<HazardData<'a>{{ capacity }}>,
+);
 
 impl<'a> HazardsData<'a> {
     /// Initializes a new [`HazardsData`] collection.
     pub fn init() -> Self {
-        Self(FnvIndexSet::new())
+        Self({{ set_type }}::new())
     }
 
     /// Initializes a new [`HazardsData`] collection from [`Hazards`].
@@ -54,10 +152,11 @@ impl<'a> HazardsData<'a> {
                 hazard.id(),
                 hazard.name(),
                 hazard.description(),
-                CategoryData::new(*hazard),
+                CategoryData::new(hazard),
+                {%- block from_hazards_risk %}{% endblock %}
             );
 
-            let _ = hazards_data.0.insert(hazard_data);
+            hazards_data.add(hazard_data);
         }
         hazards_data
     }
@@ -67,24 +166,100 @@ impl<'a> HazardsData<'a> {
         let _ = self.0.insert(hazard_data);
     }
 
+    /// Removes a [`HazardData`] from the [`HazardsData`] collection.
+    ///
+    /// Returns whether the [`HazardData`] was contained into
+    /// the collection.
+    pub fn remove(&mut self, hazard_data: &HazardData<'a>) -> bool {
+        self.0.remove(hazard_data)
+    }
+
     /// Whether the [`HazardsData`] collection is empty.
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
+    /// Returns the number of [`HazardData`]s in the [`HazardsData`] collection.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
     /// Checks whether a [`HazardData`] is contained into [`HazardsData`].
     pub fn contains(&self, hazard_data: &HazardData) -> bool {
         self.0.contains(hazard_data)
     }
 
     /// Returns an iterator over [`HazardData`]s.
-    pub fn iter(&self) -> IndexSetIter<'_, HazardData> {
+    pub fn iter(&self) -> {{ iter_type }}<'_, HazardData<'_>> {
         self.0.iter()
     }
 
     /// Merges the collection with another [`HazardsData`].
     pub fn merge(&mut self, hazards_data: &Self) {
-        self.0 = self.0.union(&hazards_data.0).cloned().collect();
+        self.extend(hazards_data.0.iter().cloned());
+    }
+
+    /// Returns the [`HazardData`]s contained in either collection.
+    pub fn union(&self, other: &Self) -> Self {
+        self.0.union(&other.0).cloned().collect()
+    }
+
+    /// Returns the [`HazardData`]s contained in both collections.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.0.intersection(&other.0).cloned().collect()
+    }
+
+    /// Returns the [`HazardData`]s contained in this collection,
+    /// but not in the other one.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.0.difference(&other.0).cloned().collect()
+    }
+
+    /// Checks whether all the [`HazardData`]s of this collection are
+    /// contained in the other one.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.is_subset(&other.0)
+    }
+
+    /// Checks whether all the [`HazardData`]s of the other collection are
+    /// contained in this one.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        self.0.is_superset(&other.0)
+    }
+
+    /// Returns the [`HazardData`]s which belong to a [`Category`].
+    pub fn filter_by_category(&self, category: Category) -> Self {
+        self.0
+            .iter()
+            .filter(|hazard_data| hazard_data.category.name == category.name())
+            .cloned()
+            .collect()
+    }
+    {%- block hazards_data_risk %}{% endblock %}
+}
+
+impl<'a> FromIterator<HazardData<'a>> for HazardsData<'a> {
+    fn from_iter<I: IntoIterator<Item = HazardData<'a>>>(iter: I) -> Self {
+        let mut hazards_data = Self::init();
+        hazards_data.extend(iter);
+        hazards_data
+    }
+}
+
+impl<'a> Extend<HazardData<'a>> for HazardsData<'a> {
+    fn extend<I: IntoIterator<Item = HazardData<'a>>>(&mut self, iter: I) {
+        for hazard_data in iter {
+            self.add(hazard_data);
+        }
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b HazardsData<'a> {
+    type Item = &'b HazardData<'a>;
+    type IntoIter = {{ iter_type }}<'b, HazardData<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
     }
 }
 
@@ -104,9 +279,37 @@ impl core::fmt::Display for Hazard {
 }
 
 impl Hazard {
+    /// Number of [`Hazard`]s.
+    pub const COUNT: usize = {{ hazards|length }};
+
+    /// All [`Hazard`]s, in the order they are declared.
+    pub const ALL: [Self; Self::COUNT] = [
+    {%- for hazard in hazards %}
+        Self::{{ hazard.name }},
+    {%- endfor %}
+    ];
+
+    /// Returns an [`Hazard`] label, as defined in the ontology.
+    pub const fn label(&self) -> &'static str {
+        match *self {
+        {%- for hazard in hazards %}
+            Self::{{ hazard.name }} => "{{ hazard.name }}",
+        {%- endfor %}
+        }
+    }
+
+    /// Returns an [`Hazard`] IRI.
+    pub const fn iri(&self) -> &'static str {
+        match *self {
+        {%- for hazard in hazards %}
+            Self::{{ hazard.name }} => "{{ hazard.iri }}",
+        {%- endfor %}
+        }
+    }
+
     /// Returns an [`Hazard`] name.
     pub const fn name(&self) -> &'static str {
-        match self {
+        match *self {
         {%- for hazard in hazards %}
             Self::{{ hazard.name }} => "{{ hazard.name|camelcase_to_whitespaces }}",
         {%- endfor %}
@@ -115,7 +318,7 @@ impl Hazard {
 
     /// Returns an [`Hazard`] description.
     pub const fn description(&self) -> &'static str {
-        match self {
+        match *self {
         {%- for hazard in hazards %}
             Self::{{ hazard.name }} => "{{ hazard.description }}.",
         {%- endfor %}
@@ -126,7 +329,7 @@ impl Hazard {
     ///
     /// An hazard **must** be associated with **only** one category.
     pub const fn category(&self) -> Category {
-        match self {
+        match *self {
         {%- for hazard in hazards %}
             Self::{{ hazard.name }} => Category::{{ hazard.category }},
         {%- endfor %}
@@ -135,9 +338,9 @@ impl Hazard {
 
     /// Returns the identifier associated with an [`Hazard`].
     pub const fn id(&self) -> u16 {
-        match self {
+        match *self {
         {%- for hazard in hazards %}
-            Self::{{ hazard.name }} => {{ loop.index0 }},
+            Self::{{ hazard.name }} => {{ hazard.id }},
         {%- endfor %}
         }
     }
@@ -151,47 +354,432 @@ impl Hazard {
     pub const fn from_id(id: u16) -> Option<Self> {
         match id {
         {%- for hazard in hazards %}
-            {{ loop.index0 }} => Some(Self::{{ hazard.name }}),
+            {{ hazard.id }} => Some(Self::{{ hazard.name }}),
         {%- endfor %}
             _ => None,
         }
     }
 }
 
+/// Parses an [`Hazard`] from its label, its name or its IRI.
+impl core::str::FromStr for Hazard {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+        {%- for hazard in hazards %}
+        {%- set name = hazard.name|camelcase_to_whitespaces %}
+            "{{ hazard.name }}"
+            {%- if name != hazard.name %}
+            | "{{ name }}"
+            {%- endif %}
+            | "{{ hazard.iri }}" => Ok(Self::{{ hazard.name }}),
+        {%- endfor %}
+            _ => Err(ParseError),
+        }
+    }
+}
+
+/// Converts an integer identifier into an [`Hazard`], as in [`Hazard::from_id`].
+impl TryFrom<u16> for Hazard {
+    type Error = InvalidId;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        Self::from_id(id).ok_or(InvalidId(id))
+    }
+}
+{{ serde_impls("Hazard", serde_representation, "an hazard " ~ serde_representation) }}
+
+/// Error returned when an [`Hazard`] or a [`Category`] cannot be parsed
+/// from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError;
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("unknown hazard or category")
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Error returned when an integer is not the identifier of an [`Hazard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidId(pub u16);
+
+impl core::fmt::Display for InvalidId {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "unknown hazard identifier {}", self.0)
+    }
+}
+
+impl core::error::Error for InvalidId {}
+
 /// A collection of [`Hazard`]s.
+///
+/// The collection is a bitmask, where the bit in the position of
+/// an [`Hazard`] identifier is set when the [`Hazard`] is contained.
+{%- if bits is defined %}
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Hazards({{ bits }});
+
+impl Hazards {
+    // Bits of the identifiers of all hazards.
+    const MASK: {{ bits }} = {% for hazard in hazards %}{% if not loop.first %}
+        | {% endif %}(1 << {{ hazard.id }}){% else %}0{% endfor %};
+
+    /// Initializes a new [`Hazards`] collection.
+    pub const fn init() -> Self {
+        Self(0)
+    }
+
+    /// Adds a new [`Hazard`] to the [`Hazards`] collection.
+    pub fn add(&mut self, hazard: Hazard) {
+        self.0 |= 1 << hazard.id();
+    }
+
+    /// Removes an [`Hazard`] from the [`Hazards`] collection.
+    ///
+    /// Returns whether the [`Hazard`] was contained into the collection.
+    pub fn remove(&mut self, hazard: Hazard) -> bool {
+        let contained = self.contains(hazard);
+        self.0 &= !(1 << hazard.id());
+        contained
+    }
+
+    /// Whether the [`Hazards`] collection is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of [`Hazard`]s in the [`Hazards`] collection.
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Checks whether an [`Hazard`] is contained into
+    /// the [`Hazards`] collection.
+    pub const fn contains(&self, hazard: Hazard) -> bool {
+        self.0 & (1 << hazard.id()) != 0
+    }
+
+    /// Returns the [`Hazard`]s contained in either collection.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the [`Hazard`]s contained in both collections.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the [`Hazard`]s contained in this collection,
+    /// but not in the other one.
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Checks whether all the [`Hazard`]s of this collection are
+    /// contained in the other one.
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Returns an iterator over [`Hazard`]s.
+    pub const fn iter(&self) -> HazardsIter {
+        HazardsIter(*self)
+    }
+}
+
+/// Creates a [`Hazards`] collection from a bitmask.
+///
+/// The bits which do not correspond to any [`Hazard`] identifier
+/// are ignored.
+impl From<u64> for Hazards {
+    fn from(bits: u64) -> Self {
+        Self(bits{% if bits == "u32" %} as u32{% endif %} & Self::MASK)
+    }
+}
+
+/// Converts a [`Hazards`] collection into a bitmask.
+impl From<Hazards> for u64 {
+    fn from(hazards: Hazards) -> Self {
+        {% if bits == "u32" %}u64::from(hazards.0){% else %}hazards.0{% endif %}
+    }
+}
+
+/// An iterator over the [`Hazard`]s of a [`Hazards`] collection,
+/// sorted by identifier.
 #[derive(Debug, Clone)]
-pub struct Hazards(FnvIndexSet<Hazard, MAXIMUM_ELEMENTS>);
+pub struct HazardsIter(Hazards);
+
+impl Iterator for HazardsIter {
+    type Item = Hazard;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bits = &mut self.0 .0;
+        while *bits != 0 {
+            let id = bits.trailing_zeros() as u16;
+            // Clear the lowest bit.
+            *bits &= *bits - 1;
+            if let Some(hazard) = Hazard::from_id(id) {
+                return Some(hazard);
+            }
+        }
+        None
+    }
+}
+{%- else %}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hazards([u64; {{ words }}]);
 
 impl Hazards {
+    // Bits of the identifiers of all hazards.
+    const MASK: [u64; {{ words }}] = [
+    {%- for word in range(words) %}
+        {% for hazard in hazards if hazard.id // 64 == word %}{% if not loop.first %}
+            | {% endif %}(1 << {{ hazard.id % 64 }}){% else %}0{% endfor %},
+    {%- endfor %}
+    ];
+
     /// Initializes a new [`Hazards`] collection.
-    pub fn init() -> Self {
-        Self(FnvIndexSet::new())
+    pub const fn init() -> Self {
+        Self([0; {{ words }}])
     }
 
     /// Adds a new [`Hazard`] to the [`Hazards`] collection.
     pub fn add(&mut self, hazard: Hazard) {
-        let _ = self.0.insert(hazard);
+        self.0[hazard.id() as usize / 64] |= 1 << (hazard.id() % 64);
+    }
+
+    /// Removes an [`Hazard`] from the [`Hazards`] collection.
+    ///
+    /// Returns whether the [`Hazard`] was contained into the collection.
+    pub fn remove(&mut self, hazard: Hazard) -> bool {
+        let contained = self.contains(hazard);
+        self.0[hazard.id() as usize / 64] &= !(1 << (hazard.id() % 64));
+        contained
     }
 
     /// Whether the [`Hazards`] collection is empty.
-    pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of [`Hazard`]s in the [`Hazards`] collection.
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < {{ words }} {
+            len += self.0[i].count_ones() as usize;
+            i += 1;
+        }
+        len
     }
 
     /// Checks whether an [`Hazard`] is contained into
     /// the [`Hazards`] collection.
-    pub fn contains(&self, hazard: Hazard) -> bool {
-        self.0.contains(&hazard)
+    pub const fn contains(&self, hazard: Hazard) -> bool {
+        self.0[hazard.id() as usize / 64] & (1 << (hazard.id() % 64)) != 0
+    }
+
+    /// Returns the [`Hazard`]s contained in either collection.
+    pub const fn union(self, other: Self) -> Self {
+        let mut words = self.0;
+        let mut i = 0;
+        while i < {{ words }} {
+            words[i] |= other.0[i];
+            i += 1;
+        }
+        Self(words)
+    }
+
+    /// Returns the [`Hazard`]s contained in both collections.
+    pub const fn intersection(self, other: Self) -> Self {
+        let mut words = self.0;
+        let mut i = 0;
+        while i < {{ words }} {
+            words[i] &= other.0[i];
+            i += 1;
+        }
+        Self(words)
+    }
+
+    /// Returns the [`Hazard`]s contained in this collection,
+    /// but not in the other one.
+    pub const fn difference(self, other: Self) -> Self {
+        let mut words = self.0;
+        let mut i = 0;
+        while i < {{ words }} {
+            words[i] &= !other.0[i];
+            i += 1;
+        }
+        Self(words)
+    }
+
+    /// Checks whether all the [`Hazard`]s of this collection are
+    /// contained in the other one.
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < {{ words }} {
+            if self.0[i] & !other.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
     }
 
     /// Returns an iterator over [`Hazard`]s.
-    pub fn iter(&self) -> IndexSetIter<'_, Hazard> {
-        self.0.iter()
+    pub const fn iter(&self) -> HazardsIter {
+        HazardsIter(*self)
+    }
+}
+
+/// Creates a [`Hazards`] collection from a bitmask, where the
+/// identifier of an [`Hazard`] is the position of its bit.
+///
+/// The bits which do not correspond to any [`Hazard`] identifier
+/// are ignored.
+impl From<[u64; {{ words }}]> for Hazards {
+    fn from(mut words: [u64; {{ words }}]) -> Self {
+        for (word, mask) in words.iter_mut().zip(Self::MASK) {
+            *word &= mask;
+        }
+        Self(words)
+    }
+}
+
+/// Converts a [`Hazards`] collection into a bitmask.
+impl From<Hazards> for [u64; {{ words }}] {
+    fn from(hazards: Hazards) -> Self {
+        hazards.0
+    }
+}
+
+/// Creates a [`Hazards`] collection from the bitmask of the [`Hazard`]s
+/// whose identifier is lower than 64.
+///
+/// The bits which do not correspond to any [`Hazard`] identifier
+/// are ignored.
+impl From<u64> for Hazards {
+    fn from(bits: u64) -> Self {
+        let mut words = [0; {{ words }}];
+        words[0] = bits & Self::MASK[0];
+        Self(words)
+    }
+}
+
+/// Converts a [`Hazards`] collection into a bitmask, failing when it
+/// contains an [`Hazard`] whose identifier is not lower than 64.
+impl TryFrom<Hazards> for u64 {
+    type Error = BitmaskOverflow;
+
+    fn try_from(hazards: Hazards) -> Result<Self, Self::Error> {
+        if hazards.0[1..].iter().any(|word| *word != 0) {
+            return Err(BitmaskOverflow);
+        }
+        Ok(hazards.0[0])
+    }
+}
+
+/// Error returned when a [`Hazards`] collection does not fit
+/// in a `u64` bitmask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitmaskOverflow;
+
+impl core::fmt::Display for BitmaskOverflow {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("hazards do not fit in a 64-bit bitmask")
+    }
+}
+
+impl core::error::Error for BitmaskOverflow {}
+
+// Arrays implement `Default` only up to 32 elements.
+impl Default for Hazards {
+    fn default() -> Self {
+        Self::init()
+    }
+}
+
+/// An iterator over the [`Hazard`]s of a [`Hazards`] collection,
+/// sorted by identifier.
+#[derive(Debug, Clone)]
+pub struct HazardsIter(Hazards);
+
+impl Iterator for HazardsIter {
+    type Item = Hazard;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, bits) in self.0 .0.iter_mut().enumerate() {
+            while *bits != 0 {
+                let id = index * 64 + bits.trailing_zeros() as usize;
+                // Clear the lowest bit.
+                *bits &= *bits - 1;
+                if let Some(hazard) = Hazard::from_id(id as u16) {
+                    return Some(hazard);
+                }
+            }
+        }
+        None
+    }
+}
+{%- endif %}
+
+impl Hazards {
+    /// Checks whether all the [`Hazard`]s of the other collection are
+    /// contained in this one.
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns the [`Hazard`]s which belong to a [`Category`].
+    pub fn filter_by_category(self, category: Category) -> Self {
+        self.iter()
+            .filter(|hazard| hazard.category() == category)
+            .collect()
+    }
+    {%- block hazards_risk %}{% endblock %}
+}
+
+impl FromIterator<Hazard> for Hazards {
+    fn from_iter<I: IntoIterator<Item = Hazard>>(iter: I) -> Self {
+        let mut hazards = Self::init();
+        hazards.extend(iter);
+        hazards
+    }
+}
+
+impl Extend<Hazard> for Hazards {
+    fn extend<I: IntoIterator<Item = Hazard>>(&mut self, iter: I) {
+        for hazard in iter {
+            self.add(hazard);
+        }
+    }
+}
+
+impl IntoIterator for Hazards {
+    type Item = Hazard;
+    type IntoIter = HazardsIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &Hazards {
+    type Item = Hazard;
+    type IntoIter = HazardsIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
 /// Hazard category data.
-#[derive(Debug, Clone, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CategoryData<'a> {
     /// Name.
     pub name: &'a str,
@@ -215,7 +803,7 @@ impl<'a> core::cmp::PartialEq for CategoryData<'a> {
 }
 
 /// Hazard categories.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Category {
 {%- for category in categories %}
     /// {{ category.description }}
@@ -230,18 +818,58 @@ impl core::fmt::Display for Category {
 }
 
 impl Category {
+    /// Number of [`Category`]s.
+    pub const COUNT: usize = {{ categories|length }};
+
+    /// All [`Category`]s, in the order they are declared.
+    pub const ALL: [Self; Self::COUNT] = [
+    {%- for category in categories %}
+        Self::{{ category.name }},
+    {%- endfor %}
+    ];
+
+    /// Returns a [`Category`] label, as defined in the ontology.
+    pub const fn label(&self) -> &'static str {
+        match *self {
+        {%- for category in categories %}
+            Self::{{ category.name }} => "{{ category.name }}",
+        {%- endfor %}
+        }
+    }
+
+    /// Returns a [`Category`] IRI.
+    pub const fn iri(&self) -> &'static str {
+        match *self {
+        {%- for category in categories %}
+            Self::{{ category.name }} => "{{ category.iri }}",
+        {%- endfor %}
+        }
+    }
+
     /// Returns a [`Category`] name.
     pub const fn name(&self) -> &'static str {
-        match self {
+        match *self {
         {%- for category in categories %}
             Self::{{ category.name }} => "{{ category.name|camelcase_to_whitespaces }}",
         {%- endfor %}
         }
     }
 
+    /// Returns a [`Category`] from its name, as returned by [`Category::name`].
+    ///
+    /// The value is [`None`] whenever the name does not exist.
+    pub const fn from_name(name: &str) -> Option<Self> {
+        match name.as_bytes() {
+        {%- for category in categories %}
+            b"{{ category.name|camelcase_to_whitespaces }}" => Some(Self::{{ category.name }}),
+        {%- endfor %}
+            _ => None,
+        }
+    }
+
     /// Returns a [`Category`] description.
     pub const fn description(&self) -> &'static str {
-        match self {
+        match *self {
         {%- for category in categories %}
             Self::{{ category.name }} => "{{ category.description }}",
         {%- endfor %}
@@ -250,7 +878,7 @@ impl Category {
 
     /// Returns all [`Hazard`]s associated with a [`Category`].
     pub const fn hazards(&self) -> &[Hazard] {
-        match self {
+        match *self {
         {%- for category in categories %}
             Self::{{ category.name }} => &[
             {%- for hazard in category.hazards %}
@@ -261,3 +889,25 @@ impl Category {
         }
     }
 }
+
+/// Parses a [`Category`] from its label, its name or its IRI.
+impl core::str::FromStr for Category {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+        {%- for category in categories %}
+        {%- set name = category.name|camelcase_to_whitespaces %}
+            "{{ category.name }}"
+            {%- if name != category.name %}
+            | "{{ name }}"
+            {%- endif %}
+            | "{{ category.iri }}" => Ok(Self::{{ category.name }}),
+        {%- endfor %}
+            _ => Err(ParseError),
+        }
+    }
+}
+{#- Categories do not have an identifier, so they are represented by their label. #}
+{%- set category_representation = "label" if serde_representation == "id" else serde_representation %}
+{{ serde_impls("Category", category_representation, "a category " ~ category_representation) }}
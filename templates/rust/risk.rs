@@ -11,7 +11,10 @@
     /// In the latter case, one of the following conditions will apply:
     /// - The risk score will have a numeric value.
     /// - The risk score will not exist at all, thus eliminating the empty string problem.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "riskScore", skip_serializing_if = "Option::is_none")
+    )]
     pub risk_score: Option<u8>,
 {%- endblock %}
 
@@ -21,11 +24,35 @@
             risk_score,
 {%- endblock %}
 
+{%- block from_hazards_risk %}
+                hazard.risk_score(),
+{%- endblock %}
+
+{%- block hazards_data_risk %}
+
+    /// Returns the highest risk score of the [`HazardData`]s,
+    /// if any of them has a risk score.
+    pub fn max_risk(&self) -> Option<u8> {
+        self.iter()
+            .filter_map(|hazard_data| hazard_data.risk_score)
+            .max()
+    }
+{%- endblock %}
+
+{%- block hazards_risk %}
+
+    /// Returns the highest risk score of the [`Hazard`]s,
+    /// if any of them has a risk score.
+    pub fn max_risk(&self) -> Option<u8> {
+        self.iter().filter_map(|hazard| hazard.risk_score()).max()
+    }
+{%- endblock %}
+
 {% block fn_risk %}
 
     /// Returns an [`Hazard`] risk score.
     pub const fn risk_score(&self) -> Option<u8> {
-        match self {
+        match *self {
         {%- for hazard in hazards %}
             Self::{{ hazard.name }} => {% if hazard.risk_score == none %}None{% else %}Some({{ hazard.risk_score }}){% endif %},
         {%- endfor %}
@@ -0,0 +1,82 @@
+/** All possible hazards for a device task. */
+export const enum Hazard {
+{%- for hazard in hazards %}
+    /** {{ hazard.description }}. */
+    {{ hazard.name }} = {{ hazard.id }},
+{%- endfor %}
+}
+
+/** Hazard categories. */
+export type Category =
+{%- for category in categories %}
+    | "{{ category.name }}"
+{%- else %} never
+{%- endfor %};
+
+/** Hazard data. */
+export interface HazardData {
+    /** Identifier. */
+    readonly id: number;
+    /** Name. */
+    readonly name: string;
+    /** Description. */
+    readonly description: string;
+    /** Category. */
+    readonly category: Category;
+    {%- block interface_risk %}{% endblock %}
+}
+
+/** Hazard category data. */
+export interface CategoryData {
+    /** Name. */
+    readonly name: string;
+    /** Description. */
+    readonly description: string;
+    /** All hazards associated with the category. */
+    readonly hazards: readonly Hazard[];
+}
+
+/** Metadata of each hazard. */
+export const HAZARDS: Readonly<Record<Hazard, HazardData>> = Object.freeze({
+{%- for hazard in hazards %}
+    [Hazard.{{ hazard.name }}]: Object.freeze({
+        id: {{ hazard.id }},
+        name: "{{ hazard.name|camelcase_to_whitespaces }}",
+        description: "{{ hazard.description }}.",
+        category: "{{ hazard.category }}",
+        {%- block value_risk scoped %}{% endblock %}
+    }),
+{%- endfor %}
+});
+
+/** Metadata of each hazard category. */
+export const CATEGORIES: Readonly<Record<Category, CategoryData>> = Object.freeze({
+{%- for category in categories %}
+    {{ category.name }}: Object.freeze({
+        name: "{{ category.name|camelcase_to_whitespaces }}",
+        description: "{{ category.description }}",
+        hazards: Object.freeze([
+        {%- for hazard in category.hazards %}
+            Hazard.{{ hazard }},
+        {%- endfor %}
+        ]),
+    }),
+{%- endfor %}
+});
+
+/**
+ * Returns a `Hazard` from an integer identifier.
+ *
+ * The value is `undefined` whenever the identifier does not exist or
+ * it is not correct.
+ */
+export function fromId(id: number): Hazard | undefined {
+    switch (id) {
+    {%- for hazard in hazards %}
+        case {{ hazard.id }}:
+            return Hazard.{{ hazard.name }};
+    {%- endfor %}
+        default:
+            return undefined;
+    }
+}
@@ -0,0 +1,10 @@
+{% extends "ts.hazards" %}
+
+{%- block interface_risk %}
+    /** Risk score, `null` whenever the hazard has no risk score. */
+    readonly riskScore: number | null;
+{%- endblock %}
+
+{%- block value_risk %}
+        riskScore: {% if hazard.risk_score == none %}null{% else %}{{ hazard.risk_score }}{% endif %},
+{%- endblock %}
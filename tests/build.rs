@@ -0,0 +1,24 @@
+mod common;
+
+use std::{env, fs, path::Path};
+
+use common::{output_dir, ONTOLOGY_PATH};
+use hazard_generator::{build, HazardsProducer};
+
+#[test]
+fn build_does_not_write_id_lock() {
+    let out_dir = output_dir("build_does_not_write_id_lock", Path::new("build"));
+    let lock_path = out_dir.join("hazards.lock");
+    let _ = fs::remove_file(&lock_path);
+
+    env::set_var("OUT_DIR", &out_dir);
+    let api_path = build::generate_with(
+        &HazardsProducer::new().with_id_lock(&lock_path),
+        [ONTOLOGY_PATH],
+    )
+    .unwrap();
+
+    assert_eq!(api_path, out_dir.join("ontology.rs"));
+    assert!(api_path.exists());
+    assert!(!lock_path.exists());
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare, compare_ontology, EMPTY_ONTOLOGY_PATH};
+use hazard_generator::TemplateType;
+
+const C_SNAPSHOTS_PATH: &str = "../snapshots/c/";
+
+#[test]
+fn header() {
+    compare(
+        "hazards.h",
+        Path::new(C_SNAPSHOTS_PATH),
+        "hazards.h",
+        TemplateType::C,
+        false,
+    );
+}
+
+#[test]
+fn source() {
+    compare(
+        "hazards.c",
+        Path::new(C_SNAPSHOTS_PATH),
+        "hazards.c",
+        TemplateType::C,
+        false,
+    );
+}
+
+#[test]
+fn header_with_risk() {
+    compare(
+        "hazards_with_risk.h",
+        Path::new(C_SNAPSHOTS_PATH),
+        "hazards.h",
+        TemplateType::C,
+        true,
+    );
+}
+
+#[test]
+fn source_with_risk() {
+    compare(
+        "hazards_with_risk.c",
+        Path::new(C_SNAPSHOTS_PATH),
+        "hazards.c",
+        TemplateType::C,
+        true,
+    );
+}
+
+#[test]
+fn empty_ontology_header() {
+    compare_ontology(
+        "empty_ontology.h",
+        Path::new(C_SNAPSHOTS_PATH),
+        "hazards.h",
+        TemplateType::C,
+        Path::new(EMPTY_ONTOLOGY_PATH),
+    );
+}
+
+#[test]
+fn empty_ontology_source() {
+    compare_ontology(
+        "empty_ontology.c",
+        Path::new(C_SNAPSHOTS_PATH),
+        "hazards.c",
+        TemplateType::C,
+        Path::new(EMPTY_ONTOLOGY_PATH),
+    );
+}
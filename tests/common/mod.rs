@@ -1,43 +1,89 @@
-use std::{env::temp_dir, fs, path::Path};
+#![allow(dead_code)]
+
+use std::{
+    env::temp_dir,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use hazard_generator::{HazardsProducer, TemplateType};
 
-const ONTOLOGY_PATH: &str = "./ontology.jsonld";
-const OUTPUT_FILE: &str = "ontology";
-const OUTPUT_WITH_RISK_FILE: &str = "ontology_with_risk";
+pub(crate) const ONTOLOGY_PATH: &str = "./ontology.jsonld";
+pub(crate) const EMPTY_ONTOLOGY_PATH: &str = "./tests/ontologies/empty.jsonld";
 
 pub(crate) fn compare(
     snapshot_name: &str,
     snapshot_path: &Path,
+    output_file: &str,
     template_type: TemplateType,
     with_risk: bool,
 ) {
-    let output_dir = temp_dir();
+    compare_with(
+        snapshot_name,
+        snapshot_path,
+        output_file,
+        template_type,
+        HazardsProducer::new().with_risk(with_risk),
+    );
+}
+
+pub(crate) fn compare_with(
+    snapshot_name: &str,
+    snapshot_path: &Path,
+    output_file: &str,
+    template_type: TemplateType,
+    producer: HazardsProducer,
+) {
+    let output_dir = output_dir(snapshot_name, snapshot_path);
+
+    producer
+        .run([Path::new(ONTOLOGY_PATH)], &output_dir, template_type)
+        .unwrap();
+
+    compare_file(snapshot_name, snapshot_path, &output_dir.join(output_file));
+}
+
+// Compares the APIs generated from an ontology other than the default one.
+pub(crate) fn compare_ontology(
+    snapshot_name: &str,
+    snapshot_path: &Path,
+    output_file: &str,
+    template_type: TemplateType,
+    ontology_path: &Path,
+) {
+    let output_dir = output_dir(snapshot_name, snapshot_path);
 
     HazardsProducer::new()
-        .with_risk(with_risk)
-        .run(Path::new(ONTOLOGY_PATH), &output_dir, template_type)
+        .run([ontology_path], &output_dir, template_type)
         .unwrap();
 
-    let output_file = match template_type {
-        TemplateType::Rust => {
-            output_dir.join(Path::new(output_file(with_risk)).with_extension("rs"))
-        }
-    };
+    compare_file(snapshot_name, snapshot_path, &output_dir.join(output_file));
+}
 
-    let ontology_api = fs::read_to_string(&output_file).unwrap();
+pub(crate) fn compare_file(snapshot_name: &str, snapshot_path: &Path, file: &Path) {
+    let contents = fs::read_to_string(file).unwrap();
 
     insta::with_settings!({
         snapshot_path => snapshot_path,
         prepend_module_to_snapshot => false,
     },{
-        insta::assert_snapshot!(snapshot_name, ontology_api);
+        insta::assert_snapshot!(snapshot_name, contents);
     });
 }
 
-fn output_file(with_risk: bool) -> &'static Path {
-    match with_risk {
-        true => Path::new(OUTPUT_WITH_RISK_FILE),
-        false => Path::new(OUTPUT_FILE),
-    }
+// Use a different output directory for each snapshot, since tests run
+// in parallel and different templates may produce files with the same name.
+pub(crate) fn output_dir(snapshot_name: &str, snapshot_path: &Path) -> PathBuf {
+    let language = snapshot_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+
+    let output_dir = temp_dir()
+        .join("hazard-generator")
+        .join(language)
+        .join(snapshot_name);
+    fs::create_dir_all(&output_dir).unwrap();
+
+    output_dir
 }
@@ -0,0 +1,99 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare_file, output_dir};
+use hazard_generator::{HazardsProducer, TemplateType};
+
+// Ontologies with a remote context are compared against the snapshots
+// produced by the bundled ontology, which embeds the same context.
+const RUST_SNAPSHOTS_PATH: &str = "../snapshots/rust/";
+const ONTOLOGY_PATH: &str = "./tests/ontologies/remote_context.jsonld";
+const ESCAPING_ONTOLOGY_PATH: &str = "./tests/ontologies/escaping_context.jsonld";
+const CONTEXTS_PATH: &str = "./tests/contexts/";
+const CONTEXT_IRI: &str = "https://purl.org/sifis/hazards/context.jsonld";
+
+fn compare_remote_context(output_name: &str, producer: HazardsProducer) {
+    let output_dir = output_dir(output_name, Path::new(RUST_SNAPSHOTS_PATH));
+
+    producer
+        .run([ONTOLOGY_PATH], &output_dir, TemplateType::Rust)
+        .unwrap();
+
+    compare_file(
+        "ontology.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        &output_dir.join("ontology.rs"),
+    );
+}
+
+#[test]
+fn context_map() {
+    compare_remote_context(
+        "context_map",
+        HazardsProducer::new()
+            .with_context(CONTEXT_IRI, Path::new(CONTEXTS_PATH).join("hazards.jsonld")),
+    );
+}
+
+#[test]
+fn context_cache() {
+    compare_remote_context(
+        "context_cache",
+        HazardsProducer::new().with_context_cache(Path::new(CONTEXTS_PATH).join("cache")),
+    );
+}
+
+#[test]
+fn escaping_context_cache() {
+    // The context authority points to the parent of the cache directory,
+    // where the context document would be found if it were not rejected.
+    let error = HazardsProducer::new()
+        .with_context_cache(Path::new(CONTEXTS_PATH).join("cache/purl.org/sifis"))
+        .run(
+            [ESCAPING_ONTOLOGY_PATH],
+            std::env::temp_dir(),
+            TemplateType::Rust,
+        )
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("JSON-LD error"));
+}
+
+#[test]
+fn missing_context() {
+    let error = HazardsProducer::new()
+        .run([ONTOLOGY_PATH], std::env::temp_dir(), TemplateType::Rust)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("JSON-LD error"));
+    assert!(error.contains(CONTEXT_IRI));
+}
+
+#[test]
+fn unreadable_context() {
+    let error = HazardsProducer::new()
+        .with_context(CONTEXT_IRI, Path::new(CONTEXTS_PATH).join("missing.jsonld"))
+        .run([ONTOLOGY_PATH], std::env::temp_dir(), TemplateType::Rust)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("JSON-LD error"));
+    assert!(error.contains(CONTEXT_IRI));
+}
+
+#[test]
+fn invalid_context_iri() {
+    let error = HazardsProducer::new()
+        .with_context(
+            "not an iri",
+            Path::new(CONTEXTS_PATH).join("hazards.jsonld"),
+        )
+        .run([ONTOLOGY_PATH], std::env::temp_dir(), TemplateType::Rust)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("JSON-LD error"));
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare, compare_ontology, EMPTY_ONTOLOGY_PATH};
+use hazard_generator::TemplateType;
+
+const CPP_SNAPSHOTS_PATH: &str = "../snapshots/cpp/";
+
+#[test]
+fn hazards() {
+    compare(
+        "hazards.hpp",
+        Path::new(CPP_SNAPSHOTS_PATH),
+        "hazards.hpp",
+        TemplateType::Cpp,
+        false,
+    );
+}
+
+#[test]
+fn hazards_with_risk() {
+    compare(
+        "hazards_with_risk.hpp",
+        Path::new(CPP_SNAPSHOTS_PATH),
+        "hazards.hpp",
+        TemplateType::Cpp,
+        true,
+    );
+}
+
+#[test]
+fn empty_ontology() {
+    compare_ontology(
+        "empty_ontology.hpp",
+        Path::new(CPP_SNAPSHOTS_PATH),
+        "hazards.hpp",
+        TemplateType::Cpp,
+        Path::new(EMPTY_ONTOLOGY_PATH),
+    );
+}
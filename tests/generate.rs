@@ -0,0 +1,63 @@
+mod common;
+
+use std::{fs, path::Path};
+
+use common::{output_dir, ONTOLOGY_PATH};
+use hazard_generator::{HazardsProducer, TemplateType};
+
+#[test]
+fn generate_relative_paths() {
+    let producer = HazardsProducer::new();
+    let ontology = producer.load([ONTOLOGY_PATH]).unwrap();
+
+    let files = producer.generate(&ontology, TemplateType::C).unwrap();
+    let paths: Vec<&Path> = files.iter().map(|file| file.path()).collect();
+
+    assert_eq!(paths, [Path::new("hazards.h"), Path::new("hazards.c")]);
+}
+
+#[test]
+fn generate_matches_render() {
+    let producer = HazardsProducer::new().with_risk(true);
+    let ontology = producer.load([ONTOLOGY_PATH]).unwrap();
+
+    let output_dir = output_dir("generate_matches_render", Path::new("generate"));
+    producer
+        .render(&ontology, &output_dir, TemplateType::Java)
+        .unwrap();
+
+    for file in producer.generate(&ontology, TemplateType::Java).unwrap() {
+        let rendered = fs::read_to_string(output_dir.join(file.path())).unwrap();
+        assert_eq!(file.contents(), rendered);
+    }
+}
+
+#[test]
+fn write_to_writer() {
+    let producer = HazardsProducer::new();
+    let ontology = producer.load([ONTOLOGY_PATH]).unwrap();
+
+    let file = producer
+        .generate(&ontology, TemplateType::Rust)
+        .unwrap()
+        .remove(0);
+
+    let mut buffer = Vec::new();
+    file.write_to(&mut buffer).unwrap();
+
+    assert_eq!(buffer, file.contents().as_bytes());
+    assert_eq!(file.into_contents().into_bytes(), buffer);
+}
+
+#[test]
+fn generate_does_not_write_id_lock() {
+    let output_dir = output_dir("generate_does_not_write_id_lock", Path::new("generate"));
+    let lock_path = output_dir.join("hazards.lock");
+    let _ = fs::remove_file(&lock_path);
+
+    let producer = HazardsProducer::new().with_id_lock(&lock_path);
+    let ontology = producer.load([ONTOLOGY_PATH]).unwrap();
+    producer.generate(&ontology, TemplateType::Rust).unwrap();
+
+    assert!(!lock_path.exists());
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use std::path::Path;
+
+use common::compare;
+use hazard_generator::TemplateType;
+
+const GO_SNAPSHOTS_PATH: &str = "../snapshots/go/";
+
+#[test]
+fn hazards() {
+    compare(
+        "hazards.go",
+        Path::new(GO_SNAPSHOTS_PATH),
+        "hazards.go",
+        TemplateType::Go,
+        false,
+    );
+}
+
+#[test]
+fn hazards_with_risk() {
+    compare(
+        "hazards_with_risk.go",
+        Path::new(GO_SNAPSHOTS_PATH),
+        "hazards.go",
+        TemplateType::Go,
+        true,
+    );
+}
@@ -0,0 +1,189 @@
+mod common;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{compare_file, output_dir, ONTOLOGY_PATH};
+use hazard_generator::{HazardsProducer, IssueKind, TemplateType};
+
+const IDENTIFIERS_SNAPSHOTS_PATH: &str = "../snapshots/identifiers/";
+const IDS_PATH: &str = "./tests/ids/";
+const ONTOLOGIES_PATH: &str = "./tests/ontologies/";
+
+// Runs the producer with a copy of a lock file, since the lock file
+// is rewritten at each run.
+fn run_with_lock(
+    snapshot_name: &str,
+    ontology: &Path,
+    lock: &str,
+    template_type: TemplateType,
+) -> PathBuf {
+    let output_dir = output_dir(snapshot_name, Path::new(IDENTIFIERS_SNAPSHOTS_PATH));
+    let lock_path = output_dir.join("hazards.lock");
+    fs::copy(Path::new(IDS_PATH).join(lock), &lock_path).unwrap();
+
+    HazardsProducer::new()
+        .with_id_lock(&lock_path)
+        .run([ontology], &output_dir, template_type)
+        .unwrap();
+
+    output_dir
+}
+
+#[test]
+fn ontology_with_id_lock() {
+    let output_dir = run_with_lock(
+        "ontology_with_id_lock.rs",
+        Path::new(ONTOLOGY_PATH),
+        "hazards.lock",
+        TemplateType::Rust,
+    );
+
+    compare_file(
+        "ontology_with_id_lock.rs",
+        Path::new(IDENTIFIERS_SNAPSHOTS_PATH),
+        &output_dir.join("ontology.rs"),
+    );
+    compare_file(
+        "hazards.lock",
+        Path::new(IDENTIFIERS_SNAPSHOTS_PATH),
+        &output_dir.join("hazards.lock"),
+    );
+}
+
+#[test]
+fn hazards_with_id_lock() {
+    let output_dir = run_with_lock(
+        "hazards_with_id_lock.proto",
+        Path::new(ONTOLOGY_PATH),
+        "hazards.lock",
+        TemplateType::Protobuf,
+    );
+
+    compare_file(
+        "hazards_with_id_lock.proto",
+        Path::new(IDENTIFIERS_SNAPSHOTS_PATH),
+        &output_dir.join("hazards.proto"),
+    );
+}
+
+#[test]
+fn explicit_identifiers() {
+    let output_dir = output_dir(
+        "explicit_identifiers.rs",
+        Path::new(IDENTIFIERS_SNAPSHOTS_PATH),
+    );
+
+    HazardsProducer::new()
+        .run(
+            [Path::new(ONTOLOGIES_PATH).join("identifiers.jsonld")],
+            &output_dir,
+            TemplateType::Rust,
+        )
+        .unwrap();
+
+    compare_file(
+        "explicit_identifiers.rs",
+        Path::new(IDENTIFIERS_SNAPSHOTS_PATH),
+        &output_dir.join("ontology.rs"),
+    );
+}
+
+#[test]
+fn missing_id_lock() {
+    let output_dir = output_dir("missing_id_lock", Path::new(IDENTIFIERS_SNAPSHOTS_PATH));
+    let lock_path = output_dir.join("hazards.lock");
+    let _ = fs::remove_file(&lock_path);
+
+    HazardsProducer::new()
+        .with_id_lock(&lock_path)
+        .run([ONTOLOGY_PATH], &output_dir, TemplateType::Rust)
+        .unwrap();
+
+    compare_file(
+        "missing_id_lock.lock",
+        Path::new(IDENTIFIERS_SNAPSHOTS_PATH),
+        &lock_path,
+    );
+}
+
+#[test]
+fn duplicate_identifiers() {
+    let error = HazardsProducer::new()
+        .run(
+            [Path::new(ONTOLOGIES_PATH).join("duplicate_identifiers.jsonld")],
+            std::env::temp_dir(),
+            TemplateType::Rust,
+        )
+        .unwrap_err();
+
+    assert!(error.to_string().starts_with("Identifier error"));
+}
+
+#[test]
+fn conflicting_id_lock() {
+    let error = HazardsProducer::new()
+        .with_id_lock(Path::new(IDS_PATH).join("conflicting.lock"))
+        .run(
+            [Path::new(ONTOLOGIES_PATH).join("identifiers.jsonld")],
+            std::env::temp_dir(),
+            TemplateType::Rust,
+        )
+        .unwrap_err();
+
+    assert!(error.to_string().starts_with("Identifier error"));
+}
+
+#[test]
+fn malformed_id_lock() {
+    let error = HazardsProducer::new()
+        .with_id_lock(Path::new(IDS_PATH).join("malformed.lock"))
+        .run([ONTOLOGY_PATH], std::env::temp_dir(), TemplateType::Rust)
+        .unwrap_err();
+
+    assert!(error.to_string().starts_with("Identifier error"));
+}
+
+#[test]
+fn out_of_range_identifier() {
+    let ontology = Path::new(ONTOLOGIES_PATH).join("out_of_range_identifier.jsonld");
+
+    let error = HazardsProducer::new()
+        .run([&ontology], std::env::temp_dir(), TemplateType::Rust)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Identifier error: Hazard https://purl.org/sifis/hazards#AirPoisoning \
+         has an invalid identifier 70000"
+    );
+
+    let issues = HazardsProducer::new().validate([&ontology]).unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(
+        issues[0].kind(),
+        &IssueKind::InvalidIdentifier("70000".into())
+    );
+}
+
+#[test]
+fn string_identifier() {
+    let ontology = Path::new(ONTOLOGIES_PATH).join("string_identifier.jsonld");
+
+    let error = HazardsProducer::new()
+        .run([&ontology], std::env::temp_dir(), TemplateType::Rust)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Identifier error: Hazard https://purl.org/sifis/hazards#AirPoisoning \
+         has an invalid identifier \"7\""
+    );
+
+    let issues = HazardsProducer::new().validate([&ontology]).unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(
+        issues[0].kind(),
+        &IssueKind::InvalidIdentifier("\"7\"".into())
+    );
+}
@@ -0,0 +1,170 @@
+mod common;
+
+use std::{fs, path::Path};
+
+use common::{compare_file, output_dir, ONTOLOGY_PATH};
+use hazard_generator::{HazardsProducer, InputFormat, TemplateType};
+use json_ld::{syntax::Parse, JsonLdProcessor, RemoteDocument};
+use oxrdfxml::RdfXmlSerializer;
+use oxttl::NTriplesParser;
+use rdf_types::RdfDisplay;
+
+// RDF ontologies are compared against the snapshots produced
+// by the JSON-LD ontology, since they contain the same triples.
+const RUST_SNAPSHOTS_PATH: &str = "../snapshots/rust/";
+const ONTOLOGIES_PATH: &str = "./tests/ontologies/";
+
+fn compare_ontology(snapshot_name: &str, ontology: &str, producer: HazardsProducer) {
+    let output_dir = output_dir(ontology, Path::new(RUST_SNAPSHOTS_PATH));
+
+    producer
+        .run(
+            [Path::new(ONTOLOGIES_PATH).join(ontology)],
+            &output_dir,
+            TemplateType::Rust,
+        )
+        .unwrap();
+
+    compare_file(
+        snapshot_name,
+        Path::new(RUST_SNAPSHOTS_PATH),
+        &output_dir.join(snapshot_name),
+    );
+}
+
+#[test]
+fn turtle() {
+    compare_ontology("ontology.rs", "ontology.ttl", HazardsProducer::new());
+}
+
+#[test]
+fn turtle_with_risk() {
+    compare_ontology(
+        "ontology_with_risk.rs",
+        "ontology.ttl",
+        HazardsProducer::new().with_risk(true),
+    );
+}
+
+#[test]
+fn n_triples() {
+    compare_ontology("ontology.rs", "ontology.nt", HazardsProducer::new());
+}
+
+#[test]
+fn n_quads() {
+    compare_ontology("ontology.rs", "ontology.nq", HazardsProducer::new());
+}
+
+// Renders the bundled JSON-LD ontology as RDF/XML.
+fn render_rdf_xml(path: &Path) {
+    let input: json_ld::RemoteDocument = RemoteDocument::new(
+        None,
+        None,
+        json_ld::syntax::Value::parse_str(&fs::read_to_string(ONTOLOGY_PATH).unwrap())
+            .unwrap()
+            .0,
+    );
+    let mut loader = json_ld::NoLoader;
+    // RDF/XML node identifiers cannot start with a digit.
+    let mut generator = rdf_types::generator::Blank::new_with_prefix("b".to_owned());
+    let mut rdf = futures::executor::block_on(input.to_rdf(&mut generator, &mut loader)).unwrap();
+
+    // Obtain the triples through their N-Triples representation.
+    let n_triples: String = rdf
+        .cloned_quads()
+        .map(|quad| format!("{} .\n", quad.rdf_display()))
+        .collect();
+
+    let mut serializer = RdfXmlSerializer::new()
+        .with_prefix("owl", "http://www.w3.org/2002/07/owl#")
+        .unwrap()
+        .with_prefix("rdfs", "http://www.w3.org/2000/01/rdf-schema#")
+        .unwrap()
+        .with_prefix("sho", "https://purl.org/sifis/hazards#")
+        .unwrap()
+        .for_writer(Vec::new());
+    for triple in NTriplesParser::new().for_slice(&n_triples) {
+        serializer.serialize_triple(&triple.unwrap()).unwrap();
+    }
+
+    fs::write(path, serializer.finish().unwrap()).unwrap();
+}
+
+fn compare_rdf_xml(snapshot_name: &str, producer: HazardsProducer) {
+    let output_dir = output_dir(
+        &format!("{snapshot_name}.owl"),
+        Path::new(RUST_SNAPSHOTS_PATH),
+    );
+    let ontology = output_dir.join("ontology.owl");
+    render_rdf_xml(&ontology);
+
+    producer
+        .run([&ontology], &output_dir, TemplateType::Rust)
+        .unwrap();
+
+    compare_file(
+        snapshot_name,
+        Path::new(RUST_SNAPSHOTS_PATH),
+        &output_dir.join(snapshot_name),
+    );
+}
+
+#[test]
+fn rdf_xml() {
+    compare_rdf_xml("ontology.rs", HazardsProducer::new());
+}
+
+#[test]
+fn rdf_xml_with_risk() {
+    compare_rdf_xml(
+        "ontology_with_risk.rs",
+        HazardsProducer::new().with_risk(true),
+    );
+}
+
+#[test]
+fn explicit_input_format() {
+    let output_dir = output_dir("explicit_input_format", Path::new(RUST_SNAPSHOTS_PATH));
+
+    // Use an extension that does not identify the format.
+    let ontology = output_dir.join("ontology.txt");
+    fs::copy(Path::new(ONTOLOGIES_PATH).join("ontology.ttl"), &ontology).unwrap();
+
+    HazardsProducer::new()
+        .with_input_format(InputFormat::Turtle)
+        .run([&ontology], &output_dir, TemplateType::Rust)
+        .unwrap();
+
+    compare_file(
+        "ontology.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        &output_dir.join("ontology.rs"),
+    );
+}
+
+#[test]
+fn malformed_turtle() {
+    let error = HazardsProducer::new()
+        .run(
+            [Path::new(ONTOLOGIES_PATH).join("malformed.ttl")],
+            std::env::temp_dir(),
+            TemplateType::Rust,
+        )
+        .unwrap_err();
+
+    assert!(error.to_string().starts_with("Ontology format error"));
+}
+
+#[test]
+fn malformed_rdf_xml() {
+    let error = HazardsProducer::new()
+        .run(
+            [Path::new(ONTOLOGIES_PATH).join("malformed.owl")],
+            std::env::temp_dir(),
+            TemplateType::Rust,
+        )
+        .unwrap_err();
+
+    assert!(error.to_string().starts_with("Ontology format error"));
+}
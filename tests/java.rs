@@ -0,0 +1,63 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare, compare_with};
+use hazard_generator::{HazardsProducer, TemplateType};
+
+const JAVA_SNAPSHOTS_PATH: &str = "../snapshots/java/";
+
+#[test]
+fn hazard() {
+    compare(
+        "Hazard.java",
+        Path::new(JAVA_SNAPSHOTS_PATH),
+        "Hazard.java",
+        TemplateType::Java,
+        false,
+    );
+}
+
+#[test]
+fn category() {
+    compare(
+        "Category.java",
+        Path::new(JAVA_SNAPSHOTS_PATH),
+        "Category.java",
+        TemplateType::Java,
+        false,
+    );
+}
+
+#[test]
+fn hazard_with_risk() {
+    compare(
+        "Hazard_with_risk.java",
+        Path::new(JAVA_SNAPSHOTS_PATH),
+        "Hazard.java",
+        TemplateType::Java,
+        true,
+    );
+}
+
+#[test]
+fn hazard_with_package() {
+    compare_with(
+        "Hazard_with_package.java",
+        Path::new(JAVA_SNAPSHOTS_PATH),
+        "Hazard.java",
+        TemplateType::Java,
+        HazardsProducer::new().with_package("eu.sifis.hazards"),
+    );
+}
+
+#[test]
+fn category_with_package() {
+    compare_with(
+        "Category_with_package.java",
+        Path::new(JAVA_SNAPSHOTS_PATH),
+        "Category.java",
+        TemplateType::Java,
+        HazardsProducer::new().with_package("eu.sifis.hazards"),
+    );
+}
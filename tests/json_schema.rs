@@ -0,0 +1,51 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare, compare_file, output_dir, ONTOLOGY_PATH};
+use hazard_generator::{HazardsProducer, RiskScoreRange, TemplateType};
+
+const JSON_SCHEMA_SNAPSHOTS_PATH: &str = "../snapshots/json-schema/";
+
+#[test]
+fn hazards() {
+    compare(
+        "hazards.schema.json",
+        Path::new(JSON_SCHEMA_SNAPSHOTS_PATH),
+        "hazards.schema.json",
+        TemplateType::JsonSchema,
+        false,
+    );
+}
+
+#[test]
+fn hazards_with_risk() {
+    compare(
+        "hazards_with_risk.schema.json",
+        Path::new(JSON_SCHEMA_SNAPSHOTS_PATH),
+        "hazards.schema.json",
+        TemplateType::JsonSchema,
+        true,
+    );
+}
+
+#[test]
+fn hazards_with_default_risk_range() {
+    let producer = HazardsProducer::new().with_risk(true);
+    let mut ontology = producer.load([ONTOLOGY_PATH]).unwrap();
+    ontology.risk_score_range = RiskScoreRange::default();
+
+    let output_dir = output_dir(
+        "hazards_with_default_risk_range.schema.json",
+        Path::new(JSON_SCHEMA_SNAPSHOTS_PATH),
+    );
+    producer
+        .render(&ontology, &output_dir, TemplateType::JsonSchema)
+        .unwrap();
+
+    compare_file(
+        "hazards_with_default_risk_range.schema.json",
+        Path::new(JSON_SCHEMA_SNAPSHOTS_PATH),
+        &output_dir.join("hazards.schema.json"),
+    );
+}
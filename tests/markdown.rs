@@ -0,0 +1,54 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare, compare_file, output_dir, ONTOLOGY_PATH};
+use hazard_generator::{HazardsProducer, RiskScoreRange, TemplateType};
+
+const MARKDOWN_SNAPSHOTS_PATH: &str = "../snapshots/markdown/";
+
+#[test]
+fn hazards() {
+    compare(
+        "hazards.md",
+        Path::new(MARKDOWN_SNAPSHOTS_PATH),
+        "hazards.md",
+        TemplateType::Markdown,
+        false,
+    );
+}
+
+#[test]
+fn hazards_with_risk() {
+    compare(
+        "hazards_with_risk.md",
+        Path::new(MARKDOWN_SNAPSHOTS_PATH),
+        "hazards.md",
+        TemplateType::Markdown,
+        true,
+    );
+}
+
+#[test]
+fn hazards_with_risk_range() {
+    let producer = HazardsProducer::new().with_risk(true);
+    let mut ontology = producer.load([ONTOLOGY_PATH]).unwrap();
+    ontology.risk_score_range = RiskScoreRange {
+        min: Some(1),
+        max: Some(5),
+    };
+
+    let output_dir = output_dir(
+        "hazards_with_risk_range.md",
+        Path::new(MARKDOWN_SNAPSHOTS_PATH),
+    );
+    producer
+        .render(&ontology, &output_dir, TemplateType::Markdown)
+        .unwrap();
+
+    compare_file(
+        "hazards_with_risk_range.md",
+        Path::new(MARKDOWN_SNAPSHOTS_PATH),
+        &output_dir.join("hazards.md"),
+    );
+}
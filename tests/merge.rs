@@ -0,0 +1,63 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare_file, output_dir, ONTOLOGY_PATH};
+use hazard_generator::{HazardsProducer, TemplateType};
+
+const MERGE_SNAPSHOTS_PATH: &str = "../snapshots/merge/";
+const ONTOLOGIES_PATH: &str = "./tests/ontologies/";
+
+#[test]
+fn merged_ontology() {
+    let output_dir = output_dir("merged_ontology.rs", Path::new(MERGE_SNAPSHOTS_PATH));
+
+    HazardsProducer::new()
+        .run(
+            [
+                Path::new(ONTOLOGY_PATH).to_path_buf(),
+                Path::new(ONTOLOGIES_PATH).join("vendor.jsonld"),
+            ],
+            &output_dir,
+            TemplateType::Rust,
+        )
+        .unwrap();
+
+    compare_file(
+        "merged_ontology.rs",
+        Path::new(MERGE_SNAPSHOTS_PATH),
+        &output_dir.join("ontology.rs"),
+    );
+}
+
+#[test]
+fn conflicting_hazard() {
+    let error = HazardsProducer::new()
+        .run(
+            [
+                Path::new(ONTOLOGY_PATH).to_path_buf(),
+                Path::new(ONTOLOGIES_PATH).join("conflicting.jsonld"),
+            ],
+            std::env::temp_dir(),
+            TemplateType::Rust,
+        )
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("Ontology merge error"));
+    assert!(error.contains("\"AirPoisoning\""));
+    assert!(error.contains("description"));
+}
+
+#[test]
+fn no_ontology() {
+    let error = HazardsProducer::new()
+        .run(
+            Vec::<&Path>::new(),
+            std::env::temp_dir(),
+            TemplateType::Rust,
+        )
+        .unwrap_err();
+
+    assert!(error.to_string().starts_with("Path format error"));
+}
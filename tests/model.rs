@@ -0,0 +1,68 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare_file, output_dir, ONTOLOGY_PATH};
+use hazard_generator::{Hazard, HazardsProducer, TemplateType};
+
+const MODEL_SNAPSHOTS_PATH: &str = "../snapshots/model/";
+
+#[test]
+fn load() {
+    let ontology = HazardsProducer::new().load([ONTOLOGY_PATH]).unwrap();
+
+    assert_eq!(
+        ontology.metadata.iri.as_deref(),
+        Some("https://purl.org/sifis/hazards")
+    );
+    assert_eq!(
+        ontology.metadata.title.as_deref(),
+        Some("The SIFIS-Home Hazards Ontology")
+    );
+    assert_eq!(ontology.metadata.version.as_deref(), Some("v1.0.1"));
+    assert_eq!(ontology.hazards.len(), 22);
+    assert_eq!(ontology.categories.len(), 3);
+    assert_eq!(ontology.risk_score_range.min, Some(0));
+    assert_eq!(ontology.risk_score_range.max, Some(10));
+
+    assert_eq!(
+        ontology.hazards[0],
+        Hazard {
+            iri: "https://purl.org/sifis/hazards#AirPoisoning".into(),
+            label: "AirPoisoning".into(),
+            name: "Air poisoning".into(),
+            description: "The execution may release toxic gases".into(),
+            comment: "A type of safety-related hazard".into(),
+            category: "https://purl.org/sifis/hazards#Safety".into(),
+            risk_score: None,
+            identifier: None,
+        }
+    );
+    assert_eq!(ontology.hazards[0].category_name(), "Safety");
+}
+
+#[test]
+fn render_modified_ontology() {
+    let producer = HazardsProducer::new();
+    let mut ontology = producer.load([ONTOLOGY_PATH]).unwrap();
+
+    ontology.hazards.push(Hazard {
+        iri: "https://vendor.example/hazards#CarbonMonoxide".into(),
+        label: "CarbonMonoxide".into(),
+        name: "Carbon monoxide".into(),
+        description: "The execution may release carbon monoxide".into(),
+        category: "https://purl.org/sifis/hazards#Safety".into(),
+        ..Default::default()
+    });
+
+    let output_dir = output_dir("modified_ontology.rs", Path::new(MODEL_SNAPSHOTS_PATH));
+    producer
+        .render(&ontology, &output_dir, TemplateType::Rust)
+        .unwrap();
+
+    compare_file(
+        "modified_ontology.rs",
+        Path::new(MODEL_SNAPSHOTS_PATH),
+        &output_dir.join("ontology.rs"),
+    );
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare, compare_ontology, EMPTY_ONTOLOGY_PATH};
+use hazard_generator::TemplateType;
+
+const PROTOBUF_SNAPSHOTS_PATH: &str = "../snapshots/protobuf/";
+
+#[test]
+fn hazards() {
+    compare(
+        "hazards.proto",
+        Path::new(PROTOBUF_SNAPSHOTS_PATH),
+        "hazards.proto",
+        TemplateType::Protobuf,
+        false,
+    );
+}
+
+#[test]
+fn hazards_with_risk() {
+    compare(
+        "hazards_with_risk.proto",
+        Path::new(PROTOBUF_SNAPSHOTS_PATH),
+        "hazards.proto",
+        TemplateType::Protobuf,
+        true,
+    );
+}
+
+#[test]
+fn empty_ontology() {
+    compare_ontology(
+        "empty_ontology.proto",
+        Path::new(PROTOBUF_SNAPSHOTS_PATH),
+        "hazards.proto",
+        TemplateType::Protobuf,
+        Path::new(EMPTY_ONTOLOGY_PATH),
+    );
+}
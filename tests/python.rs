@@ -0,0 +1,30 @@
+mod common;
+
+use std::path::Path;
+
+use common::compare;
+use hazard_generator::TemplateType;
+
+const PYTHON_SNAPSHOTS_PATH: &str = "../snapshots/python/";
+
+#[test]
+fn hazards() {
+    compare(
+        "hazards.py",
+        Path::new(PYTHON_SNAPSHOTS_PATH),
+        "hazards.py",
+        TemplateType::Python,
+        false,
+    );
+}
+
+#[test]
+fn hazards_with_risk() {
+    compare(
+        "hazards_with_risk.py",
+        Path::new(PYTHON_SNAPSHOTS_PATH),
+        "hazards.py",
+        TemplateType::Python,
+        true,
+    );
+}
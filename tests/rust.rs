@@ -2,16 +2,21 @@ mod common;
 
 use std::path::Path;
 
-use common::compare;
-use hazard_generator::TemplateType;
+use common::{
+    compare, compare_file, compare_ontology, compare_with, output_dir, EMPTY_ONTOLOGY_PATH,
+    ONTOLOGY_PATH,
+};
+use hazard_generator::{HazardsProducer, Profile, SerdeRepresentation, TemplateType};
 
 const RUST_SNAPSHOTS_PATH: &str = "../snapshots/rust/";
+const TEMPLATES_PATH: &str = "./tests/templates/";
 
 #[test]
 fn ontology() {
     compare(
         "ontology.rs",
         Path::new(RUST_SNAPSHOTS_PATH),
+        "ontology.rs",
         TemplateType::Rust,
         false,
     );
@@ -22,7 +27,230 @@ fn ontology_with_risk() {
     compare(
         "ontology_with_risk.rs",
         Path::new(RUST_SNAPSHOTS_PATH),
+        "ontology_with_risk.rs",
         TemplateType::Rust,
         true,
     );
 }
+
+#[test]
+fn ontology_alloc() {
+    compare_with(
+        "ontology_alloc.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "ontology.rs",
+        TemplateType::Rust,
+        HazardsProducer::new().with_rust_profile(Profile::Alloc),
+    );
+}
+
+#[test]
+fn ontology_std() {
+    compare_with(
+        "ontology_std.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "ontology.rs",
+        TemplateType::Rust,
+        HazardsProducer::new().with_rust_profile(Profile::Std),
+    );
+}
+
+#[test]
+fn ontology_serde_id() {
+    compare_with(
+        "ontology_serde_id.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "ontology.rs",
+        TemplateType::Rust,
+        HazardsProducer::new().with_serde_representation(SerdeRepresentation::Id),
+    );
+}
+
+#[test]
+fn ontology_serde_iri() {
+    compare_with(
+        "ontology_serde_iri.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "ontology.rs",
+        TemplateType::Rust,
+        HazardsProducer::new().with_serde_representation(SerdeRepresentation::Iri),
+    );
+}
+
+#[test]
+fn ontology_with_template_dir() {
+    compare_with(
+        "ontology_with_template_dir.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "ontology.rs",
+        TemplateType::Rust,
+        HazardsProducer::new().with_template_dir(Path::new(TEMPLATES_PATH).join("custom")),
+    );
+}
+
+#[test]
+fn broken_template() {
+    let error = HazardsProducer::new()
+        .with_template_dir(Path::new(TEMPLATES_PATH).join("broken"))
+        .run([ONTOLOGY_PATH], std::env::temp_dir(), TemplateType::Rust)
+        .unwrap_err();
+
+    assert!(error.to_string().starts_with("Template error"));
+}
+
+#[test]
+fn missing_template() {
+    let error = HazardsProducer::new()
+        .with_template_dir(Path::new(TEMPLATES_PATH).join("missing"))
+        .run([ONTOLOGY_PATH], std::env::temp_dir(), TemplateType::Rust)
+        .unwrap_err();
+
+    assert!(error.to_string().starts_with("Template error"));
+}
+
+#[test]
+fn crate_manifest() {
+    compare_with(
+        "crate_Cargo.toml",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "Cargo.toml",
+        TemplateType::Rust,
+        HazardsProducer::new().with_rust_crate("sifis-hazards"),
+    );
+}
+
+#[test]
+fn invalid_crate_name() {
+    for crate_name in ["", "1hazards", "sifis hazards", "sifis\"hazards", "hazàrds"] {
+        let error = HazardsProducer::new()
+            .with_rust_crate(crate_name)
+            .run([ONTOLOGY_PATH], std::env::temp_dir(), TemplateType::Rust)
+            .unwrap_err();
+
+        assert!(error.to_string().starts_with("Template error"));
+    }
+}
+
+#[test]
+fn crate_lib() {
+    compare_with(
+        "crate_lib.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "src/lib.rs",
+        TemplateType::Rust,
+        HazardsProducer::new().with_rust_crate("sifis-hazards"),
+    );
+}
+
+#[test]
+fn crate_alloc_manifest() {
+    compare_with(
+        "crate_alloc_Cargo.toml",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "Cargo.toml",
+        TemplateType::Rust,
+        HazardsProducer::new()
+            .with_rust_crate("sifis-hazards")
+            .with_rust_profile(Profile::Alloc),
+    );
+}
+
+#[test]
+fn crate_alloc_lib() {
+    compare_with(
+        "crate_alloc_lib.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "src/lib.rs",
+        TemplateType::Rust,
+        HazardsProducer::new()
+            .with_rust_crate("sifis-hazards")
+            .with_rust_profile(Profile::Alloc),
+    );
+}
+
+#[test]
+fn crate_std_manifest() {
+    compare_with(
+        "crate_std_Cargo.toml",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "Cargo.toml",
+        TemplateType::Rust,
+        HazardsProducer::new()
+            .with_rust_crate("sifis-hazards")
+            .with_rust_profile(Profile::Std),
+    );
+}
+
+#[test]
+fn crate_std_lib() {
+    compare_with(
+        "crate_std_lib.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "src/lib.rs",
+        TemplateType::Rust,
+        HazardsProducer::new()
+            .with_rust_crate("sifis-hazards")
+            .with_rust_profile(Profile::Std),
+    );
+}
+
+#[test]
+fn crate_ontology() {
+    let producer = HazardsProducer::new();
+    let ontology = producer.load([ONTOLOGY_PATH]).unwrap();
+
+    let file = producer
+        .generate(&ontology, TemplateType::Rust)
+        .unwrap()
+        .remove(0);
+    let crate_files = HazardsProducer::new()
+        .with_rust_crate("sifis-hazards")
+        .generate(&ontology, TemplateType::Rust)
+        .unwrap();
+
+    // The ontology module of a crate is the lone Rust source file.
+    assert_eq!(crate_files[2].path(), Path::new("src/ontology.rs"));
+    assert_eq!(crate_files[2].contents(), file.contents());
+}
+
+// Renders the ontology with hazard identifiers starting from `first_id`,
+// so that a wider bitmask is needed to store a set of hazards.
+fn compare_with_identifiers(snapshot_name: &str, first_id: u16) {
+    let producer = HazardsProducer::new();
+    let mut ontology = producer.load([ONTOLOGY_PATH]).unwrap();
+    for (hazard, id) in ontology.hazards.iter_mut().zip(first_id..) {
+        hazard.identifier = Some(id);
+    }
+
+    let output_dir = output_dir(snapshot_name, Path::new(RUST_SNAPSHOTS_PATH));
+    producer
+        .render(&ontology, &output_dir, TemplateType::Rust)
+        .unwrap();
+
+    compare_file(
+        snapshot_name,
+        Path::new(RUST_SNAPSHOTS_PATH),
+        &output_dir.join("ontology.rs"),
+    );
+}
+
+#[test]
+fn ontology_u64_bitmask() {
+    compare_with_identifiers("ontology_u64_bitmask.rs", 40);
+}
+
+#[test]
+fn ontology_array_bitmask() {
+    compare_with_identifiers("ontology_array_bitmask.rs", 120);
+}
+
+#[test]
+fn empty_ontology() {
+    compare_ontology(
+        "empty_ontology.rs",
+        Path::new(RUST_SNAPSHOTS_PATH),
+        "ontology.rs",
+        TemplateType::Rust,
+        Path::new(EMPTY_ONTOLOGY_PATH),
+    );
+}
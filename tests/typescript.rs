@@ -0,0 +1,41 @@
+mod common;
+
+use std::path::Path;
+
+use common::{compare, compare_ontology, EMPTY_ONTOLOGY_PATH};
+use hazard_generator::TemplateType;
+
+const TYPESCRIPT_SNAPSHOTS_PATH: &str = "../snapshots/typescript/";
+
+#[test]
+fn hazards() {
+    compare(
+        "hazards.ts",
+        Path::new(TYPESCRIPT_SNAPSHOTS_PATH),
+        "hazards.ts",
+        TemplateType::TypeScript,
+        false,
+    );
+}
+
+#[test]
+fn hazards_with_risk() {
+    compare(
+        "hazards_with_risk.ts",
+        Path::new(TYPESCRIPT_SNAPSHOTS_PATH),
+        "hazards.ts",
+        TemplateType::TypeScript,
+        true,
+    );
+}
+
+#[test]
+fn empty_ontology() {
+    compare_ontology(
+        "empty_ontology.ts",
+        Path::new(TYPESCRIPT_SNAPSHOTS_PATH),
+        "hazards.ts",
+        TemplateType::TypeScript,
+        Path::new(EMPTY_ONTOLOGY_PATH),
+    );
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use std::path::Path;
+
+use common::ONTOLOGY_PATH;
+use hazard_generator::{HazardsProducer, IssueKind};
+
+const VALIDATION_SNAPSHOTS_PATH: &str = "snapshots/validation/";
+const ONTOLOGIES_PATH: &str = "./tests/ontologies/";
+
+#[test]
+fn valid_ontology() {
+    let issues = HazardsProducer::new().validate([ONTOLOGY_PATH]).unwrap();
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn invalid_ontology() {
+    let issues = HazardsProducer::new()
+        .validate([Path::new(ONTOLOGIES_PATH).join("invalid.jsonld")])
+        .unwrap();
+
+    let report = issues
+        .iter()
+        .map(|issue| issue.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    insta::with_settings!({
+        snapshot_path => VALIDATION_SNAPSHOTS_PATH,
+        prepend_module_to_snapshot => false,
+    },{
+        insta::assert_snapshot!("invalid_ontology", report);
+    });
+}
+
+#[test]
+fn duplicate_risk_score_range() {
+    let ontology = Path::new(ONTOLOGIES_PATH).join("duplicate_risk_score_range.jsonld");
+
+    let issues = HazardsProducer::new().validate([&ontology]).unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind(), &IssueKind::DuplicateRiskScoreRange);
+
+    let error = HazardsProducer::new().load([&ontology]).unwrap_err();
+    assert!(error.to_string().starts_with("Ontology format error"));
+}